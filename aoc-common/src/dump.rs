@@ -0,0 +1,81 @@
+//! Dumps of a day's parsed input, to check the parser against the real input. Dumps are requested
+//! by running a day with `--dump-parsed FORMAT`, in which case the day prints the dump instead of
+//! solving the puzzle. Available with the `serde` feature.
+
+use serde::Serialize;
+
+/// The formats a parsed input can be dumped as.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DumpFormat {
+    Json,
+}
+
+impl DumpFormat {
+    /// Serialize a parsed input in this format.
+    pub fn dump<T: Serialize>(&self, parsed: &T) -> String {
+        match self {
+            DumpFormat::Json => {
+                serde_json::to_string_pretty(parsed).expect("Unable to serialize to JSON") + "\n"
+            }
+        }
+    }
+}
+
+/// Get the format requested on the command line with `--dump-parsed FORMAT`, if any.
+pub fn dump_parsed_flag() -> Option<DumpFormat> {
+    parse_dump_parsed_flag(std::env::args().skip(1))
+}
+
+fn parse_dump_parsed_flag(args: impl IntoIterator<Item = String>) -> Option<DumpFormat> {
+    let mut args = args.into_iter().skip_while(|a| a != "--dump-parsed");
+    args.next()?;
+
+    match args.next().as_deref() {
+        Some("json") => Some(DumpFormat::Json),
+        Some(format) => panic!("Unsupported dump format: {}", format),
+        None => panic!("Missing dump format, expected --dump-parsed json"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--trace"], None)]
+    #[case(&["--dump-parsed", "json"], Some(DumpFormat::Json))]
+    #[case(&["--trace", "--dump-parsed", "json"], Some(DumpFormat::Json))]
+    fn test_parse_dump_parsed_flag(#[case] args: &[&str], #[case] expected: Option<DumpFormat>) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_dump_parsed_flag(args), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Unsupported dump format: yaml")]
+    fn test_parse_dump_parsed_flag_unsupported() {
+        parse_dump_parsed_flag(["--dump-parsed".to_string(), "yaml".to_string()]);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Missing dump format")]
+    fn test_parse_dump_parsed_flag_missing() {
+        parse_dump_parsed_flag(["--dump-parsed".to_string()]);
+    }
+
+    #[derive(Serialize)]
+    struct Parsed {
+        x: i64,
+        y: i64,
+    }
+
+    #[rstest]
+    fn test_dump_json() {
+        let dump = DumpFormat::Json.dump(&vec![Parsed { x: 1, y: 2 }]);
+
+        assert_eq!(dump, "[\n  {\n    \"x\": 1,\n    \"y\": 2\n  }\n]\n");
+    }
+}
@@ -0,0 +1,20 @@
+//! Geometry primitives shared by the grid puzzles. Available with the `geometry` feature.
+
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Point<T>
+where
+    T: Clone + Copy,
+{
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T>
+where
+    T: Clone + Copy,
+{
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
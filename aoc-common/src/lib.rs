@@ -1,31 +1,367 @@
 use itertools::Itertools;
-use std::fmt::Debug;
-use std::fs::{read_to_string, File};
-use std::io::{BufRead, BufReader};
+use std::cell::RefCell;
+use std::ffi::OsString;
+use std::fmt::{Debug, Display};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Read};
 use std::ops::{Add, Mul, Sub};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
-use textwrap::dedent;
+use std::time::{Duration, Instant};
 
-pub fn get_input(filename: &str) -> Vec<String> {
-    let path = format!("{}/../input/{}", env!("CARGO_MANIFEST_DIR"), filename);
-    let file = match File::open(path) {
-        Ok(file) => file,
-        Err(error) => panic!("Unable to open file {}: {}", filename, error),
-    };
+#[cfg(feature = "serde")]
+pub mod dump;
+#[cfg(feature = "geometry")]
+mod geometry;
+#[cfg(feature = "progress")]
+pub mod progress;
+#[cfg(feature = "viz")]
+pub mod viz;
+
+#[cfg(feature = "geometry")]
+pub use geometry::Point;
+
+thread_local! {
+    static INPUT_DIR: RefCell<Option<PathBuf>> = const { RefCell::new(None) };
+}
+
+/// Read the inputs of the current thread from another directory than `input/` until this guard
+/// is dropped, e.g. to test the loading functions against synthetic files.
+pub struct InputDirOverride {
+    previous: Option<PathBuf>,
+}
+
+impl InputDirOverride {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let previous = INPUT_DIR.with_borrow_mut(|d| d.replace(dir.into()));
+
+        Self { previous }
+    }
+}
+
+impl Drop for InputDirOverride {
+    fn drop(&mut self) {
+        INPUT_DIR.with_borrow_mut(|d| *d = self.previous.take());
+    }
+}
+
+/// Get the path of an input file. Inputs are read from `input/`, unless another directory is set
+/// for the current thread with [`InputDirOverride`] or for the whole process with
+/// `AOC_INPUT_DIR`.
+fn get_input_path(filename: &str) -> PathBuf {
+    INPUT_DIR.with_borrow(|dir| match dir {
+        Some(dir) => dir.join(filename),
+        None => match std::env::var_os("AOC_INPUT_DIR") {
+            Some(dir) => Path::new(&dir).join(filename),
+            None => Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("../input")
+                .join(filename),
+        },
+    })
+}
+
+/// Get the path of the gzipped version of an input file, `dayNN.txt.gz` for `dayNN.txt`.
+fn get_gz_path(path: &Path) -> PathBuf {
+    let mut gz_path = OsString::from(path);
+    gz_path.push(".gz");
+
+    gz_path.into()
+}
+
+/// Get the URL to read the input from, given on the command line with `--input-url URL`, if any.
+pub fn input_url_flag() -> Option<String> {
+    parse_input_url_flag(std::env::args().skip(1))
+}
+
+fn parse_input_url_flag(args: impl IntoIterator<Item = String>) -> Option<String> {
+    let mut args = args.into_iter().skip_while(|a| a != "--input-url");
+    args.next()?;
+
+    match args.next() {
+        Some(url) => Some(url),
+        None => panic!("Missing URL, expected --input-url URL"),
+    }
+}
+
+#[cfg(feature = "download")]
+/// Get the arguments to fetch an input with curl. Inputs from adventofcode.com are personal, so
+/// the session cookie is sent with them, and only with them.
+fn get_curl_args(url: &str, session_cookie: Option<&str>) -> Vec<String> {
+    let mut args = vec![
+        "--fail".to_string(),
+        "--silent".to_string(),
+        "--show-error".to_string(),
+        "--location".to_string(),
+    ];
+
+    if url.starts_with("https://adventofcode.com/") {
+        let cookie = session_cookie
+            .expect("SESSION_COOKIE is required to fetch inputs from adventofcode.com");
+        args.extend(["--cookie".to_string(), format!("session={}", cookie)]);
+    }
+
+    args.push(url.to_string());
+
+    args
+}
+
+#[cfg(feature = "download")]
+/// Fetch an input with curl, like `just prepare` does.
+fn fetch_input(url: &str) -> io::Result<Vec<u8>> {
+    let session_cookie = std::env::var("SESSION_COOKIE").ok();
+    let output = std::process::Command::new("curl")
+        .args(get_curl_args(url, session_cookie.as_deref()))
+        .output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(output.stdout)
+}
+
+#[cfg(not(feature = "download"))]
+fn fetch_input(_url: &str) -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "built without the download feature",
+    ))
+}
+
+/// Check whether the puzzle input should be read from the system clipboard, with `--clipboard`.
+pub fn clipboard_flag() -> bool {
+    parse_clipboard_flag(std::env::args().skip(1))
+}
+
+fn parse_clipboard_flag(args: impl IntoIterator<Item = String>) -> bool {
+    args.into_iter().any(|arg| arg == "--clipboard")
+}
+
+#[cfg(feature = "clipboard")]
+fn read_clipboard() -> io::Result<Vec<u8>> {
+    arboard::Clipboard::new()
+        .and_then(|mut c| c.get_text())
+        .map(String::into_bytes)
+        .map_err(io::Error::other)
+}
+
+#[cfg(not(feature = "clipboard"))]
+fn read_clipboard() -> io::Result<Vec<u8>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "built without the clipboard feature",
+    ))
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum InputSource {
+    /// A file of the input directory, as read by [`get_input`].
+    File(String),
+    /// A URL, fetched with curl.
+    Url(String),
+    /// The system clipboard.
+    Clipboard,
+}
 
-    let reader = BufReader::new(file);
+impl InputSource {
+    /// Get the source of a day's puzzle input from the command line: the clipboard with
+    /// `--clipboard`, the URL given with `--input-url URL`, or the day's input file otherwise. This is for the days' binaries, the
+    /// loading functions never look at the command line themselves.
+    pub fn from_args(filename: &str) -> Self {
+        Self::parse_args(filename, std::env::args().skip(1))
+    }
+
+    fn parse_args(filename: &str, args: impl IntoIterator<Item = String>) -> Self {
+        let args: Vec<String> = args.into_iter().collect();
+
+        if parse_clipboard_flag(args.iter().cloned()) {
+            return InputSource::Clipboard;
+        }
+
+        match parse_input_url_flag(args) {
+            Some(url) => InputSource::Url(url),
+            None => InputSource::File(filename.to_string()),
+        }
+    }
+
+    fn open(&self) -> io::Result<Box<dyn Read>> {
+        match self {
+            InputSource::File(filename) => open_input(filename),
+            InputSource::Url(url) => Ok(Box::new(io::Cursor::new(fetch_input(url)?))),
+            InputSource::Clipboard => Ok(Box::new(io::Cursor::new(read_clipboard()?))),
+        }
+    }
+
+    /// Read the input line by line.
+    pub fn read(&self) -> Vec<String> {
+        let file = match self.open() {
+            Ok(file) => file,
+            Err(error) => panic!("Unable to open {}: {}", self, error),
+        };
+
+        let reader = BufReader::new(file);
+
+        reader
+            .lines()
+            .map(|l| l.unwrap_or_else(|e| panic!("Unable to read {}: {}", self, e)))
+            .collect()
+    }
+
+    /// Read the input as a single string, without its leading and trailing whitespace.
+    pub fn read_as_string(&self) -> String {
+        let mut file = match self.open() {
+            Ok(file) => file,
+            Err(error) => panic!("Unable to open {}: {}", self, error),
+        };
+
+        let mut reader = String::new();
+        if let Err(error) = file.read_to_string(&mut reader) {
+            panic!("Unable to read {}: {}", self, error);
+        }
+
+        reader.trim().to_owned()
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputSource::File(filename) => write!(f, "file {}", filename),
+            InputSource::Url(url) => write!(f, "URL {}", url),
+            InputSource::Clipboard => write!(f, "clipboard"),
+        }
+    }
+}
+
+/// Open an input file, decompressing it if it's gzipped. When `dayNN.txt` is missing,
+/// `dayNN.txt.gz` is read instead, so archived inputs can be used as is. Gzipped inputs need the
+/// `gz-input` feature.
+fn open_input(filename: &str) -> io::Result<Box<dyn Read>> {
+    let path = get_input_path(filename);
+
+    if path.extension().is_some_and(|e| e == "gz") {
+        return gunzip(File::open(path)?);
+    }
+
+    match File::open(&path) {
+        Ok(file) => Ok(Box::new(file)),
+        Err(error) if error.kind() == io::ErrorKind::NotFound && cfg!(feature = "gz-input") => {
+            match File::open(get_gz_path(&path)) {
+                Ok(file) => gunzip(file),
+                Err(_) => Err(error),
+            }
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(feature = "gz-input")]
+fn gunzip(file: File) -> io::Result<Box<dyn Read>> {
+    Ok(Box::new(flate2::read::GzDecoder::new(file)))
+}
 
-    reader.lines().map(|l| l.unwrap()).collect()
+#[cfg(not(feature = "gz-input"))]
+fn gunzip(_file: File) -> io::Result<Box<dyn Read>> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        "built without the gz-input feature",
+    ))
+}
+
+pub fn get_input(filename: &str) -> Vec<String> {
+    InputSource::File(filename.to_string()).read()
 }
 
 pub fn get_input_as_string(filename: &str) -> String {
-    let path = format!("{}/../input/{}", env!("CARGO_MANIFEST_DIR"), filename);
-    let reader = match read_to_string(path) {
-        Ok(r) => r,
-        Err(error) => panic!("Unable to open file {}: {}", filename, error),
-    };
+    InputSource::File(filename.to_string()).read_as_string()
+}
+
+/// Get the name of an example input file, relative to the input directory:
+/// `examples/dayNN.txt`, or `examples/dayNN.variant.txt` for the variants of a day's examples.
+pub fn get_example_filename(day: u8, variant: Option<&str>) -> String {
+    match variant {
+        Some(v) => format!("examples/day{:02}.{}.txt", day, v),
+        None => format!("examples/day{:02}.txt", day),
+    }
+}
+
+/// Read one of a day's example inputs from `input/examples`.
+pub fn get_example_input(day: u8, variant: Option<&str>) -> Vec<String> {
+    get_input(&get_example_filename(day, variant))
+}
+
+/// Read one of a day's example inputs from `input/examples` as a single string.
+pub fn get_example_input_as_string(day: u8, variant: Option<&str>) -> String {
+    get_input_as_string(&get_example_filename(day, variant))
+}
+
+/// An error in a puzzle input, pointing at the line that couldn't be parsed.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ParseError {
+    pub day: &'static str,
+    /// The 1-based number of the line in the input.
+    pub line_no: usize,
+    pub line: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(day: &'static str, line_no: usize, line: &str, reason: impl Into<String>) -> Self {
+        Self {
+            day,
+            line_no,
+            line: line.to_owned(),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} line {}: {} in {:?}",
+            self.day, self.line_no, self.reason, self.line
+        )
+    }
+}
 
-    reader.trim().parse().unwrap()
+impl std::error::Error for ParseError {}
+
+/// Parse each line of an input, turning the reason returned by `parse_line` on failure into a
+/// [`ParseError`] pointing at the line.
+pub fn parse_lines<T>(
+    day: &'static str,
+    input: &[String],
+    parse_line: impl Fn(&str) -> Result<T, String>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(idx, line)| parse_line(line).map_err(|e| ParseError::new(day, idx + 1, line, e)))
+        .collect()
+}
+
+/// Check whether a puzzle's input file is available. Puzzle inputs are personal, so they might be
+/// missing from a fresh clone. The gzipped version only counts with the `gz-input` feature.
+pub fn input_exists(filename: &str) -> bool {
+    let path = get_input_path(filename);
+
+    path.is_file() || (cfg!(feature = "gz-input") && get_gz_path(&path).is_file())
+}
+
+/// Skip the rest of a test, reporting it on stderr, when the given puzzle input file is missing.
+#[macro_export]
+macro_rules! require_input {
+    ($filename:expr) => {
+        if !$crate::input_exists($filename) {
+            eprintln!("Skipping test: input/{} is missing", $filename);
+            return;
+        }
+    };
 }
 
 pub fn get_input_as_int<T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Ord + FromStr>(
@@ -40,6 +376,16 @@ where
         .collect()
 }
 
+/// Check whether intermediate structures were requested on the command line with `--trace`. Days
+/// that support it print them after their answers.
+pub fn trace_flag() -> bool {
+    parse_trace_flag(std::env::args().skip(1))
+}
+
+fn parse_trace_flag(args: impl IntoIterator<Item = String>) -> bool {
+    args.into_iter().any(|arg| arg == "--trace")
+}
+
 pub fn format_duration(nanos: u128) -> String {
     let elapsed = nanos as f64 / 1000.0;
 
@@ -50,6 +396,26 @@ pub fn format_duration(nanos: u128) -> String {
     }
 }
 
+/// Remove the leading whitespace common to all the non-blank lines. Blank lines are emptied.
+fn dedent(input: &str) -> String {
+    let indent = |line: &str| line.len() - line.trim_start().len();
+    let prefix = input
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| &l[..indent(l)])
+        .reduce(|a, b| {
+            let common = a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count();
+            &a[..common]
+        })
+        .unwrap_or("");
+
+    input
+        .split('\n')
+        .map(|l| l.strip_prefix(prefix).unwrap_or(l.trim_start()))
+        .map(|l| if l.trim().is_empty() { "" } else { l })
+        .join("\n")
+}
+
 /// Parse a puzzle's input data provided as a multi line string. The input is dedented first, then
 /// the first and last lines are removed if they are empty.
 /// This is useful for providing test input as a string.
@@ -68,30 +434,600 @@ pub fn parse_test_input_as_string(input: &str) -> String {
     dedent(input).trim().to_owned()
 }
 
-#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq)]
-pub struct Point<T>
-where
-    T: Clone + Copy,
-{
-    pub x: T,
-    pub y: T,
+/// Get the expected answer for a part of a day's puzzle, as recorded in `answers.toml`.
+#[cfg(feature = "test-helpers")]
+pub fn get_answer(day: &str, part: u8) -> Option<String> {
+    let path = format!("{}/../answers.toml", env!("CARGO_MANIFEST_DIR"));
+    let answers = match std::fs::read_to_string(path) {
+        Ok(r) => r,
+        Err(error) => panic!("Unable to open answers.toml: {}", error),
+    };
+
+    let answers: toml::Table = answers.parse().expect("Invalid answers.toml");
+
+    match answers.get(day)?.get(format!("part{}", part))? {
+        toml::Value::String(s) => Some(s.clone()),
+        v => Some(v.to_string()),
+    }
+}
+
+/// Check a puzzle result against the expected answer recorded in `answers.toml`.
+#[cfg(feature = "test-helpers")]
+pub fn assert_answer(day: &str, part: u8, result: impl Display) {
+    let expected = get_answer(day, part)
+        .unwrap_or_else(|| panic!("No answer for {} part {} in answers.toml", day, part));
+
+    assert_eq!(
+        result.to_string(),
+        expected,
+        "Wrong answer for {} part {}",
+        day,
+        part
+    );
+}
+
+/// Skip the rest of a test, reporting it on stderr, when no answer is recorded in `answers.toml`
+/// for the given part of a day's puzzle.
+#[cfg(feature = "test-helpers")]
+#[macro_export]
+macro_rules! require_answer {
+    ($day:expr, $part:expr) => {
+        if $crate::get_answer($day, $part).is_none() {
+            eprintln!(
+                "Skipping test: no answer for {} part {} in answers.toml",
+                $day, $part
+            );
+            return;
+        }
+    };
+}
+
+/// Check that all the implementations of a solution return the same result, to catch variants
+/// that only diverge on the real input. Each variant is given with a name, and all the results
+/// are listed when they don't agree.
+pub fn assert_variants_agree<T: PartialEq + Debug>(variants: &[(&str, &dyn Fn() -> T)]) {
+    let results: Vec<(&str, T)> = variants.iter().map(|(name, f)| (*name, f())).collect();
+
+    if results.iter().any(|(_, r)| *r != results[0].1) {
+        let listing = results
+            .iter()
+            .map(|(name, r)| format!("  {}: {:?}", name, r))
+            .join("\n");
+
+        panic!("Variants disagree:\n{}", listing);
+    }
+}
+
+/// Generate the `test_p1_full_input` and `test_p2_full_input` tests of a day, checking the result
+/// of each part's function, called with the puzzle input, against `answers.toml`. The tests are
+/// skipped when the day's input file or the part's answer is missing.
+#[cfg(feature = "test-helpers")]
+#[macro_export]
+macro_rules! answer_tests {
+    ($day:literal, $input:expr, $p1:expr, $p2:expr $(,)?) => {
+        #[test]
+        fn test_p1_full_input() {
+            $crate::require_input!(concat!($day, ".txt"));
+            $crate::require_answer!($day, 1);
+            $crate::assert_answer($day, 1, ($p1)($input));
+        }
+
+        #[test]
+        fn test_p2_full_input() {
+            $crate::require_input!(concat!($day, ".txt"));
+            $crate::require_answer!($day, 2);
+            $crate::assert_answer($day, 2, ($p2)($input));
+        }
+    };
+}
+
+/// An example of a day: its input file, relative to `input/`, and the expected answers of the
+/// parts it applies to.
+#[cfg(feature = "test-helpers")]
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Example {
+    pub file: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Get the examples of a day, as listed in `input/examples/examples.toml`, followed by the ones
+/// found in the community test data layout under `input/tests`.
+#[cfg(feature = "test-helpers")]
+pub fn get_examples(day: &str) -> Vec<Example> {
+    let mut examples = get_manifest_examples(day);
+    examples.extend(get_community_examples(day));
+
+    examples
+}
+
+#[cfg(feature = "test-helpers")]
+fn get_manifest_examples(day: &str) -> Vec<Example> {
+    if !input_exists("examples/examples.toml") {
+        return Vec::new();
+    }
+
+    let manifest = get_input_as_string("examples/examples.toml");
+    let manifest: toml::Table = manifest.parse().expect("Invalid examples.toml");
+
+    let answer = |entry: &toml::Value, part: &str| match entry.get(part)? {
+        toml::Value::String(s) => Some(s.clone()),
+        v => Some(v.to_string()),
+    };
+
+    let Some(entries) = manifest.get(day) else {
+        return Vec::new();
+    };
+
+    entries
+        .as_array()
+        .unwrap_or_else(|| panic!("Invalid examples.toml: {} must be a list of examples", day))
+        .iter()
+        .map(|entry| Example {
+            file: format!(
+                "examples/{}",
+                entry
+                    .get("file")
+                    .and_then(toml::Value::as_str)
+                    .unwrap_or_else(|| panic!(
+                        "Invalid examples.toml: {} example without file",
+                        day
+                    ))
+            ),
+            part1: answer(entry, "part1"),
+            part2: answer(entry, "part2"),
+        })
+        .collect()
+}
+
+/// Get the examples of a day in the community test data layout: a `tests/dayNN` directory with an
+/// `input` file and the answers in `expected_p1` and `expected_p2`, each optional. Several cases
+/// can be given as subdirectories of `tests/dayNN` with the same files.
+#[cfg(feature = "test-helpers")]
+fn get_community_examples(day: &str) -> Vec<Example> {
+    let dir = format!("tests/{}", day);
+    let Ok(entries) = std::fs::read_dir(get_input_path(&dir)) else {
+        return Vec::new();
+    };
+
+    let cases = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| format!("{}/{}", dir, e.file_name().to_string_lossy()))
+        .sorted();
+
+    std::iter::once(dir.clone())
+        .chain(cases)
+        .filter(|case| input_exists(&format!("{}/input", case)))
+        .map(|case| {
+            let answer = |part: u8| {
+                let filename = format!("{}/expected_p{}", case, part);
+                input_exists(&filename).then(|| get_input_as_string(&filename).trim().to_owned())
+            };
+
+            Example {
+                file: format!("{}/input", case),
+                part1: answer(1),
+                part2: answer(2),
+            }
+        })
+        .collect()
+}
+
+/// An input that can be read from a file, either line by line or as a single string.
+#[cfg(feature = "test-helpers")]
+pub trait FromInputFile {
+    fn from_input_file(filename: &str) -> Self;
+}
+
+#[cfg(feature = "test-helpers")]
+impl FromInputFile for Vec<String> {
+    fn from_input_file(filename: &str) -> Self {
+        get_input(filename)
+    }
+}
+
+#[cfg(feature = "test-helpers")]
+impl FromInputFile for String {
+    fn from_input_file(filename: &str) -> Self {
+        get_input_as_string(filename)
+    }
 }
 
-impl<T> Point<T>
+/// Check each part's function against the answers of every example of a day, as given by
+/// [`get_examples`]. A part is only checked on the examples that give its answer, and all the
+/// mismatches are listed.
+#[cfg(feature = "test-helpers")]
+pub fn assert_examples<I1, I2, R1, R2>(day: &str, p1: impl Fn(I1) -> R1, p2: impl Fn(I2) -> R2)
 where
-    T: Clone + Copy,
+    I1: FromInputFile,
+    I2: FromInputFile,
+    R1: Display,
+    R2: Display,
 {
-    pub fn new(x: T, y: T) -> Self {
-        Self { x, y }
+    let examples = get_examples(day);
+    assert!(
+        !examples.is_empty(),
+        "No examples for {} in examples.toml or tests/",
+        day
+    );
+
+    let mut failures = Vec::new();
+    let mut check = |file: &str, part: u8, expected: &str, result: String| {
+        if result != expected {
+            failures.push(format!(
+                "  {} part {}: expected {}, got {}",
+                file, part, expected, result
+            ));
+        }
+    };
+
+    for example in examples {
+        if let Some(expected) = &example.part1 {
+            let result = p1(I1::from_input_file(&example.file)).to_string();
+            check(&example.file, 1, expected, result);
+        }
+
+        if let Some(expected) = &example.part2 {
+            let result = p2(I2::from_input_file(&example.file)).to_string();
+            check(&example.file, 2, expected, result);
+        }
+    }
+
+    if !failures.is_empty() {
+        panic!(
+            "Wrong answers for {} examples:\n{}",
+            day,
+            failures.join("\n")
+        );
+    }
+}
+
+/// Generate the `test_examples` test of a day, checking the result of each part's function
+/// against the day's examples in `input/examples/examples.toml` and `input/tests`.
+#[cfg(feature = "test-helpers")]
+#[macro_export]
+macro_rules! example_tests {
+    ($day:literal, $p1:expr, $p2:expr $(,)?) => {
+        #[test]
+        fn test_examples() {
+            $crate::assert_examples($day, $p1, $p2);
+        }
+    };
+}
+
+/// Run a day's binary with its inputs read from `input_dir`, through `AOC_INPUT_DIR`.
+#[cfg(feature = "test-helpers")]
+pub fn run_binary(binary: &str, input_dir: &Path) -> std::process::Output {
+    std::process::Command::new(binary)
+        .env("AOC_INPUT_DIR", input_dir)
+        .output()
+        .unwrap_or_else(|e| panic!("Unable to run {}: {}", binary, e))
+}
+
+/// Run a day's binary on each of its examples, as given by [`get_examples`], and check that it
+/// succeeds and prints the answers in the expected format: `Part 1: ...`, `Part 2: ...` and
+/// `Duration: ...`, one per line. The binary solves both parts, so only the examples giving the
+/// answers of both parts are used: leaving out an answer keeps the example out of these tests. The
+/// examples are copied as the day's input in a directory under `tmp_dir`.
+#[cfg(feature = "test-helpers")]
+pub fn assert_binary_examples(day: &str, binary: &str, tmp_dir: &Path) {
+    let examples: Vec<Example> = get_examples(day)
+        .into_iter()
+        .filter(|e| e.part1.is_some() && e.part2.is_some())
+        .collect();
+    assert!(
+        !examples.is_empty(),
+        "No example of {} with both answers in examples.toml or tests/",
+        day
+    );
+
+    for example in examples {
+        let input_dir = tmp_dir.join(format!("binary-{}", example.file.replace('/', "-")));
+        std::fs::create_dir_all(&input_dir).unwrap();
+        std::fs::copy(
+            get_input_path(&example.file),
+            input_dir.join(format!("{}.txt", day)),
+        )
+        .unwrap_or_else(|e| panic!("Unable to copy {}: {}", example.file, e));
+
+        let output = run_binary(binary, &input_dir);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            output.status.success(),
+            "{} failed on {} ({}):\n{}",
+            day,
+            example.file,
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        let lines: Vec<&str> = stdout.lines().collect();
+        assert_eq!(
+            lines.len(),
+            3,
+            "Unexpected output for {}:\n{}",
+            example.file,
+            stdout
+        );
+        for (part, (line, expected)) in lines.iter().zip([example.part1, example.part2]).enumerate()
+        {
+            assert_eq!(
+                *line,
+                format!("Part {}: {}", part + 1, expected.unwrap()),
+                "Wrong output for {}",
+                example.file
+            );
+        }
+        assert!(
+            lines[2].starts_with("Duration: "),
+            "Unexpected duration line for {}: {}",
+            example.file,
+            lines[2]
+        );
+    }
+}
+
+/// Generate the integration tests of a day's binary: `test_binary_examples`, running it on the
+/// day's examples with [`assert_binary_examples`], and `test_binary_missing_input`, checking that
+/// it fails without an input. To be used in the day's `tests/` directory.
+#[cfg(feature = "test-helpers")]
+#[macro_export]
+macro_rules! binary_tests {
+    ($day:literal) => {
+        #[test]
+        fn test_binary_examples() {
+            $crate::assert_binary_examples(
+                $day,
+                env!(concat!("CARGO_BIN_EXE_", $day)),
+                std::path::Path::new(env!("CARGO_TARGET_TMPDIR")),
+            );
+        }
+
+        #[test]
+        fn test_binary_missing_input() {
+            let input_dir = std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("binary-empty");
+            std::fs::create_dir_all(&input_dir).unwrap();
+
+            let output = $crate::run_binary(env!(concat!("CARGO_BIN_EXE_", $day)), &input_dir);
+            let stderr = String::from_utf8_lossy(&output.stderr);
+
+            assert!(!output.status.success());
+            assert!(output.stdout.is_empty());
+            assert!(
+                stderr.contains(concat!("Unable to open file ", $day, ".txt")),
+                "Unexpected error:\n{}",
+                stderr
+            );
+        }
+    };
+}
+
+/// Check whether the performance tests were requested, by setting `AOC_PERF`. They are only
+/// meaningful with optimizations, so they are also skipped in debug builds.
+pub fn perf_tests_enabled() -> bool {
+    if std::env::var_os("AOC_PERF").is_none() {
+        eprintln!("Skipping test: set AOC_PERF=1 to run performance tests");
+        return false;
+    }
+
+    if cfg!(debug_assertions) {
+        eprintln!("Skipping test: performance tests need a release build");
+        return false;
+    }
+
+    true
+}
+
+/// Get the median duration of `runs` calls to a function.
+pub fn median_duration(runs: usize, mut f: impl FnMut()) -> Duration {
+    assert!(runs > 0, "At least one run is needed");
+
+    let mut durations: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+
+    durations.sort();
+    durations[runs / 2]
+}
+
+/// Check that the median duration of a day's solution stays within its budget. The number of
+/// runs can be set with `AOC_PERF_RUNS` and defaults to 9.
+pub fn assert_within_budget(day: &str, budget: Duration, f: impl FnMut()) {
+    let runs = match std::env::var("AOC_PERF_RUNS") {
+        Ok(r) => r.parse().expect("Invalid AOC_PERF_RUNS"),
+        Err(_) => 9,
+    };
+
+    let median = median_duration(runs, f);
+
+    assert!(
+        median <= budget,
+        "{} took {} (median of {} runs), over its budget of {}",
+        day,
+        format_duration(median.as_nanos()),
+        runs,
+        format_duration(budget.as_nanos())
+    );
+}
+
+/// Generate the `test_solve_performance` test of a day, checking that solving the puzzle input
+/// takes less than the given budget, in milliseconds. The test only runs in release builds with
+/// `AOC_PERF` set, e.g. `AOC_PERF=1 cargo test --release performance`.
+#[macro_export]
+macro_rules! perf_test {
+    ($day:literal, $budget_ms:literal, $input:expr, $solve:expr $(,)?) => {
+        #[test]
+        fn test_solve_performance() {
+            if !$crate::perf_tests_enabled() {
+                return;
+            }
+            $crate::require_input!(concat!($day, ".txt"));
+
+            let input = $input;
+            $crate::assert_within_budget(
+                $day,
+                std::time::Duration::from_millis($budget_ms),
+                || {
+                    let _ = std::hint::black_box(($solve)(&input));
+                },
+            );
+        }
+    };
+}
+
+/// Define an rstest fixture returning an example input, either given inline and parsed with
+/// [`parse_test_input`], or read from `input/examples` with [`get_example_input`] when given as
+/// `day = N` (and optionally `variant = "name"`). The fixture is named `test_input` unless a name
+/// is given, and `as String` makes it return the input as a single string instead.
+#[macro_export]
+macro_rules! example_fixture {
+    ($input:literal) => {
+        $crate::example_fixture!(test_input, $input);
+    };
+    ($input:literal as String) => {
+        $crate::example_fixture!(test_input, $input as String);
+    };
+    (day = $day:literal $(, variant = $variant:literal)?) => {
+        $crate::example_fixture!(test_input, day = $day $(, variant = $variant)?);
+    };
+    (day = $day:literal $(, variant = $variant:literal)? as String) => {
+        $crate::example_fixture!(test_input, day = $day $(, variant = $variant)? as String);
+    };
+    ($name:ident, $input:literal) => {
+        #[rstest::fixture]
+        fn $name() -> Vec<String> {
+            $crate::parse_test_input($input)
+        }
+    };
+    ($name:ident, $input:literal as String) => {
+        #[rstest::fixture]
+        fn $name() -> String {
+            $crate::parse_test_input_as_string($input)
+        }
+    };
+    ($name:ident, day = $day:literal $(, variant = $variant:literal)?) => {
+        #[rstest::fixture]
+        fn $name() -> Vec<String> {
+            $crate::get_example_input($day, None $(.or(Some($variant)))?)
+        }
+    };
+    ($name:ident, day = $day:literal $(, variant = $variant:literal)? as String) => {
+        #[rstest::fixture]
+        fn $name() -> String {
+            $crate::get_example_input_as_string($day, None $(.or(Some($variant)))?)
+        }
+    };
+}
+
+/// Define the `puzzle_input` rstest fixture, returning the content of the given input file with
+/// [`get_input`], or with [`get_input_as_string`] when followed by `as String`.
+#[macro_export]
+macro_rules! puzzle_fixture {
+    ($filename:literal) => {
+        #[rstest::fixture]
+        fn puzzle_input() -> Vec<String> {
+            $crate::get_input($filename)
+        }
+    };
+    ($filename:literal as String) => {
+        #[rstest::fixture]
+        fn puzzle_input() -> String {
+            $crate::get_input_as_string($filename)
+        }
+    };
+}
+
+/// Strategies for property tests, available with the `proptest` feature.
+#[cfg(feature = "proptest")]
+pub mod strategies {
+    use proptest::prelude::*;
+
+    use super::Point;
+
+    impl<T> Arbitrary for Point<T>
+    where
+        T: Arbitrary + Copy,
+        T::Parameters: Clone,
+    {
+        type Parameters = T::Parameters;
+        type Strategy = prop::strategy::Map<(T::Strategy, T::Strategy), fn((T, T)) -> Self>;
+
+        fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+            (any_with::<T>(args.clone()), any_with::<T>(args)).prop_map(|(x, y)| Point::new(x, y))
+        }
+    }
+
+    /// Points within a `width` by `height` grid.
+    pub fn points_within(width: usize, height: usize) -> impl Strategy<Value = Point<usize>> {
+        (0..width, 0..height).prop_map(|(x, y)| Point::new(x, y))
+    }
+
+    /// Grids of up to `max_width` by `max_height` cells taken from `alphabet`, as the lines of a
+    /// puzzle input. All the lines have the same length, and grids are never empty.
+    pub fn grid_lines(
+        max_width: usize,
+        max_height: usize,
+        alphabet: &'static [char],
+    ) -> impl Strategy<Value = Vec<String>> {
+        assert!(!alphabet.is_empty(), "The alphabet can't be empty");
+
+        (1..=max_width, 1..=max_height).prop_flat_map(move |(width, height)| {
+            let line = prop::collection::vec(prop::sample::select(alphabet), width)
+                .prop_map(|cells| cells.into_iter().collect::<String>());
+
+            prop::collection::vec(line, height)
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use rstest::rstest;
+    use rstest::{fixture, rstest};
+    use tempfile::TempDir;
 
     use super::*;
 
+    /// A temporary input directory, used instead of `input/` by the current test.
+    struct TestInputDir {
+        _override: InputDirOverride,
+        dir: TempDir,
+    }
+
+    impl TestInputDir {
+        fn write(&self, filename: &str, content: &str) {
+            let path = self.dir.path().join(filename);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+
+        #[cfg(feature = "gz-input")]
+        fn write_gz(&self, filename: &str, content: &str) {
+            use flate2::write::GzEncoder;
+            use flate2::Compression;
+            use std::io::Write;
+
+            let file = File::create(self.dir.path().join(filename)).unwrap();
+            let mut encoder = GzEncoder::new(file, Compression::default());
+            encoder.write_all(content.as_bytes()).unwrap();
+            encoder.finish().unwrap();
+        }
+    }
+
+    #[fixture]
+    fn input_dir() -> TestInputDir {
+        let dir = TempDir::new().unwrap();
+
+        TestInputDir {
+            _override: InputDirOverride::new(dir.path()),
+            dir,
+        }
+    }
+
     #[rstest]
     fn test_parse_input() {
         let input = "abc
@@ -165,6 +1101,97 @@ foobar";
         assert_eq!(expected, parse_test_input_as_string(input));
     }
 
+    #[rstest]
+    #[case(&[], false)]
+    #[case(&["--visualize"], false)]
+    #[case(&["--visualize", "--trace"], true)]
+    #[case(&["--trace=all"], false)]
+    fn test_parse_trace_flag(#[case] args: &[&str], #[case] expected: bool) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_trace_flag(args), expected);
+    }
+
+    #[rstest]
+    #[case(&[], false)]
+    #[case(&["--trace"], false)]
+    #[case(&["--clipboard"], true)]
+    fn test_parse_clipboard_flag(#[case] args: &[&str], #[case] expected: bool) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_clipboard_flag(args), expected);
+    }
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--trace"], None)]
+    #[case(&["--input-url", "https://example.com/day01.txt"], Some("https://example.com/day01.txt"))]
+    fn test_parse_input_url_flag(#[case] args: &[&str], #[case] expected: Option<&str>) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_input_url_flag(args).as_deref(), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Missing URL")]
+    fn test_parse_input_url_flag_missing() {
+        parse_input_url_flag(["--input-url".to_string()]);
+    }
+
+    #[rstest]
+    #[case(&[], InputSource::File("day01.txt".to_string()))]
+    #[case(&["--trace"], InputSource::File("day01.txt".to_string()))]
+    #[case(
+        &["--input-url", "https://example.com/day01.txt"],
+        InputSource::Url("https://example.com/day01.txt".to_string())
+    )]
+    #[case(&["--clipboard"], InputSource::Clipboard)]
+    #[case(
+        &["--input-url", "https://example.com/day01.txt", "--clipboard"],
+        InputSource::Clipboard
+    )]
+    fn test_input_source_parse_args(#[case] args: &[&str], #[case] expected: InputSource) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(InputSource::parse_args("day01.txt", args), expected);
+    }
+
+    #[rstest]
+    #[case(InputSource::File("day01.txt".to_string()), "file day01.txt")]
+    #[case(InputSource::Url("https://example.com".to_string()), "URL https://example.com")]
+    #[case(InputSource::Clipboard, "clipboard")]
+    fn test_input_source_display(#[case] source: InputSource, #[case] expected: &str) {
+        assert_eq!(source.to_string(), expected);
+    }
+
+    #[cfg(feature = "download")]
+    #[rstest]
+    #[case("https://example.com/day01.txt", Some("abc"), vec![])]
+    #[case("https://example.com/day01.txt", None, vec![])]
+    #[case(
+        "https://adventofcode.com/2023/day/1/input",
+        Some("abc"),
+        vec!["--cookie", "session=abc"]
+    )]
+    fn test_get_curl_args(
+        #[case] url: &str,
+        #[case] session_cookie: Option<&str>,
+        #[case] expected_cookie_args: Vec<&str>,
+    ) {
+        let mut expected = vec!["--fail", "--silent", "--show-error", "--location"];
+        expected.extend(expected_cookie_args);
+        expected.push(url);
+
+        assert_eq!(get_curl_args(url, session_cookie), expected);
+    }
+
+    #[cfg(feature = "download")]
+    #[rstest]
+    #[should_panic(expected = "SESSION_COOKIE is required")]
+    fn test_get_curl_args_without_session_cookie() {
+        get_curl_args("https://adventofcode.com/2023/day/1/input", None);
+    }
+
     #[rstest]
     #[case(1, "0.001μs")]
     #[case(1000, "1.000μs")]
@@ -175,4 +1202,403 @@ foobar";
     fn test_format_duration(#[case] nanos: u128, #[case] expected: &str) {
         assert_eq!(format_duration(nanos), expected);
     }
+
+    #[rstest]
+    #[case("day01.txt", true)]
+    #[case("day00.txt", false)]
+    fn test_input_exists(#[case] filename: &str, #[case] expected: bool) {
+        assert_eq!(input_exists(filename), expected);
+    }
+
+    #[rstest]
+    #[case(5, None, "examples/day05.txt")]
+    #[case(8, Some("p1"), "examples/day08.p1.txt")]
+    fn test_get_example_filename(
+        #[case] day: u8,
+        #[case] variant: Option<&str>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(get_example_filename(day, variant), expected);
+    }
+
+    #[rstest]
+    fn test_get_example_input() {
+        let input = get_example_input(8, Some("p1_alternate"));
+
+        assert_eq!(
+            input,
+            vec![
+                "LLR",
+                "",
+                "AAA = (BBB, BBB)",
+                "BBB = (AAA, ZZZ)",
+                "ZZZ = (ZZZ, ZZZ)"
+            ]
+        );
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    fn test_get_answer() {
+        assert_eq!(get_answer("day01", 1), Some(String::from("56049")));
+        assert_eq!(get_answer("day01", 3), None);
+        assert_eq!(get_answer("day00", 1), None);
+    }
+
+    #[rstest]
+    fn test_assert_variants_agree() {
+        assert_variants_agree(&[("a", &|| 42), ("b", &|| 40 + 2), ("c", &|| 6 * 7)]);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Variants disagree:\n  a: 42\n  b: 41")]
+    fn test_assert_variants_agree_mismatch() {
+        assert_variants_agree(&[("a", &|| 42), ("b", &|| 41)]);
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    fn test_get_examples(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\npart2 = \"abc\"\n\n\
+             [[day00]]\nfile = \"day00.p2.txt\"\npart2 = 5\n",
+        );
+
+        assert_eq!(
+            get_examples("day00"),
+            vec![
+                Example {
+                    file: String::from("examples/day00.txt"),
+                    part1: Some(String::from("3")),
+                    part2: Some(String::from("abc")),
+                },
+                Example {
+                    file: String::from("examples/day00.p2.txt"),
+                    part1: None,
+                    part2: Some(String::from("5")),
+                },
+            ]
+        );
+        assert_eq!(get_examples("day01"), vec![]);
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    fn test_get_examples_community_layout(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\n",
+        );
+        input_dir.write("tests/day00/input", "1\n2\n3\n");
+        input_dir.write("tests/day00/expected_p1", "3\n");
+        input_dir.write("tests/day00/expected_p2", "6\n");
+        input_dir.write("tests/day00/edge-case/input", "1\n");
+        input_dir.write("tests/day00/edge-case/expected_p2", "2\n");
+        input_dir.write("tests/day00/empty/notes.md", "no input here\n");
+
+        assert_eq!(
+            get_examples("day00"),
+            vec![
+                Example {
+                    file: String::from("examples/day00.txt"),
+                    part1: Some(String::from("3")),
+                    part2: None,
+                },
+                Example {
+                    file: String::from("tests/day00/input"),
+                    part1: Some(String::from("3")),
+                    part2: Some(String::from("6")),
+                },
+                Example {
+                    file: String::from("tests/day00/edge-case/input"),
+                    part1: None,
+                    part2: Some(String::from("2")),
+                },
+            ]
+        );
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    fn test_assert_examples_community_layout(input_dir: TestInputDir) {
+        input_dir.write("tests/day00/input", "1\n2\n3\n");
+        input_dir.write("tests/day00/expected_p1", "3");
+        input_dir.write("tests/day00/expected_p2", "6");
+
+        assert_examples(
+            "day00",
+            |input: Vec<String>| input.len(),
+            |input: Vec<String>| input.len() * 2,
+        );
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    fn test_assert_examples(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\n\n\
+             [[day00]]\nfile = \"day00.p2.txt\"\npart2 = \"foobar\"\n",
+        );
+        input_dir.write("examples/day00.txt", "1\n2\n3\n");
+        input_dir.write("examples/day00.p2.txt", "foo\nbar\n");
+
+        assert_examples(
+            "day00",
+            |input: Vec<String>| input.len(),
+            |input: String| input.replace('\n', ""),
+        );
+    }
+
+    #[cfg(feature = "test-helpers")]
+    #[rstest]
+    #[should_panic(expected = "Wrong answers for day00 examples:\n  \
+                               examples/day00.txt part 1: expected 4, got 3\n  \
+                               examples/day00.txt part 2: expected 5, got 6")]
+    fn test_assert_examples_mismatch(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 4\npart2 = 5\n",
+        );
+        input_dir.write("examples/day00.txt", "1\n2\n3\n");
+
+        assert_examples(
+            "day00",
+            |input: Vec<String>| input.len(),
+            |input: Vec<String>| input.len() * 2,
+        );
+    }
+
+    /// Write a script printing `output`, standing for a day's binary.
+    #[cfg(all(unix, feature = "test-helpers"))]
+    fn write_fake_binary(dir: &Path, output: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.join("fake-binary");
+        std::fs::write(&path, format!("#!/bin/sh\nprintf '{}'\n", output)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+
+        path
+    }
+
+    #[cfg(all(unix, feature = "test-helpers"))]
+    #[rstest]
+    fn test_assert_binary_examples_skips_partial_examples(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\npart2 = 6\n\n\
+             [[day00]]\nfile = \"day00.p1.txt\"\npart1 = 1\n",
+        );
+        input_dir.write("examples/day00.txt", "1\n2\n3\n");
+        input_dir.write("examples/day00.p1.txt", "1\n");
+        let tmp_dir = TempDir::new().unwrap();
+        let binary = write_fake_binary(
+            tmp_dir.path(),
+            "Part 1: 3\\nPart 2: 6\\nDuration: 1.000μs\\n",
+        );
+
+        assert_binary_examples("day00", binary.to_str().unwrap(), tmp_dir.path());
+    }
+
+    #[cfg(all(unix, feature = "test-helpers"))]
+    #[rstest]
+    #[should_panic(expected = "No example of day00 with both answers")]
+    fn test_assert_binary_examples_without_complete_example(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\n",
+        );
+        input_dir.write("examples/day00.txt", "1\n2\n3\n");
+        let tmp_dir = TempDir::new().unwrap();
+        let binary = write_fake_binary(tmp_dir.path(), "");
+
+        assert_binary_examples("day00", binary.to_str().unwrap(), tmp_dir.path());
+    }
+
+    #[cfg(all(unix, feature = "test-helpers"))]
+    #[rstest]
+    #[should_panic(expected = "Wrong output for examples/day00.txt")]
+    fn test_assert_binary_examples_mismatch(input_dir: TestInputDir) {
+        input_dir.write(
+            "examples/examples.toml",
+            "[[day00]]\nfile = \"day00.txt\"\npart1 = 3\npart2 = 6\n",
+        );
+        input_dir.write("examples/day00.txt", "1\n2\n3\n");
+        let tmp_dir = TempDir::new().unwrap();
+        let binary = write_fake_binary(
+            tmp_dir.path(),
+            "Part 1: 3\\nPart 2: 5\\nDuration: 1.000μs\\n",
+        );
+
+        assert_binary_examples("day00", binary.to_str().unwrap(), tmp_dir.path());
+    }
+
+    #[rstest]
+    fn test_median_duration() {
+        let mut delays = [200u64, 10, 50].into_iter();
+
+        let median = median_duration(3, || {
+            std::thread::sleep(Duration::from_millis(delays.next().unwrap()))
+        });
+
+        assert!(median >= Duration::from_millis(50));
+        assert!(median < Duration::from_millis(200));
+    }
+
+    #[rstest]
+    fn test_parse_lines() {
+        let input = parse_test_input(
+            "
+            1
+            22
+            333
+            ",
+        );
+
+        assert_eq!(
+            parse_lines("day00", &input, |l| l
+                .parse::<u32>()
+                .map_err(|e| e.to_string())),
+            Ok(vec![1, 22, 333])
+        );
+    }
+
+    #[rstest]
+    fn test_parse_lines_error() {
+        let input = parse_test_input(
+            "
+            1
+            2x
+            3
+            ",
+        );
+
+        let err = parse_lines("day00", &input, |l| {
+            l.parse::<u32>()
+                .map_err(|_| String::from("expected a number"))
+        })
+        .unwrap_err();
+
+        assert_eq!(err, ParseError::new("day00", 2, "2x", "expected a number"));
+        assert_eq!(err.to_string(), "day00 line 2: expected a number in \"2x\"");
+    }
+
+    #[rstest]
+    #[case("abc\n123\nfoobar", vec!["abc", "123", "foobar"])]
+    #[case("abc\n123\nfoobar\n", vec!["abc", "123", "foobar"])]
+    #[case("abc\r\n123\r\nfoobar\r\n", vec!["abc", "123", "foobar"])]
+    #[case("abc\n\nfoobar\n", vec!["abc", "", "foobar"])]
+    #[case("", vec![])]
+    fn test_get_input(input_dir: TestInputDir, #[case] content: &str, #[case] expected: Vec<&str>) {
+        input_dir.write("day00.txt", content);
+
+        assert_eq!(get_input("day00.txt"), expected);
+    }
+
+    #[rstest]
+    #[case("rn=1,cm-\n", "rn=1,cm-")]
+    #[case("  rn=1,cm-\r\n", "rn=1,cm-")]
+    #[case("abc\r\n123\r\n", "abc\r\n123")]
+    fn test_get_input_as_string(
+        input_dir: TestInputDir,
+        #[case] content: &str,
+        #[case] expected: &str,
+    ) {
+        input_dir.write("day00.txt", content);
+
+        assert_eq!(get_input_as_string("day00.txt"), expected);
+    }
+
+    #[cfg(feature = "gz-input")]
+    #[rstest]
+    #[case("day00.txt")]
+    #[case("day00.txt.gz")]
+    fn test_get_input_gzipped(input_dir: TestInputDir, #[case] filename: &str) {
+        input_dir.write_gz("day00.txt.gz", "abc\r\n123\n");
+
+        assert_eq!(get_input(filename), vec!["abc", "123"]);
+        assert_eq!(get_input_as_string(filename), "abc\r\n123");
+        assert!(input_exists(filename));
+    }
+
+    #[cfg(feature = "gz-input")]
+    #[rstest]
+    fn test_get_input_prefers_uncompressed(input_dir: TestInputDir) {
+        input_dir.write("day00.txt", "plain\n");
+        input_dir.write_gz("day00.txt.gz", "gzipped\n");
+
+        assert_eq!(get_input("day00.txt"), vec!["plain"]);
+    }
+
+    #[cfg(feature = "gz-input")]
+    #[rstest]
+    #[should_panic(expected = "Unable to read file day00.txt.gz")]
+    fn test_get_input_invalid_gzip(input_dir: TestInputDir) {
+        input_dir.write("day00.txt.gz", "not gzipped\n");
+
+        get_input("day00.txt.gz");
+    }
+
+    #[rstest]
+    fn test_get_input_as_int(input_dir: TestInputDir) {
+        input_dir.write("day00.txt", "1\r\n-22\r\n333\r\n");
+
+        assert_eq!(get_input_as_int::<i32>("day00.txt"), vec![1, -22, 333]);
+    }
+
+    #[rstest]
+    fn test_get_example_input_from_override(input_dir: TestInputDir) {
+        input_dir.write("examples/day00.txt", "abc\n123\n");
+        input_dir.write("examples/day00.p2.txt", "foobar\n");
+
+        assert_eq!(get_example_input(0, None), vec!["abc", "123"]);
+        assert_eq!(get_example_input(0, Some("p2")), vec!["foobar"]);
+    }
+
+    #[rstest]
+    fn test_input_exists_with_override(input_dir: TestInputDir) {
+        input_dir.write("day00.txt", "abc\n");
+
+        assert!(input_exists("day00.txt"));
+        assert!(!input_exists("day01.txt"));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Unable to open file day00.txt")]
+    fn test_get_input_missing_file(#[from(input_dir)] _input_dir: TestInputDir) {
+        get_input("day00.txt");
+    }
+
+    #[rstest]
+    fn test_input_dir_override_is_restored() {
+        {
+            let _guard = InputDirOverride::new("/nonexistent");
+            assert!(!input_exists("day01.txt"));
+        }
+
+        assert!(input_exists("day01.txt"));
+    }
+
+    #[cfg(feature = "proptest")]
+    proptest::proptest! {
+        #[test]
+        fn prop_points_within_bounds(p in strategies::points_within(7, 3)) {
+            proptest::prop_assert!(p.x < 7 && p.y < 3);
+        }
+
+        #[test]
+        fn prop_grid_lines_are_rectangular(
+            grid in strategies::grid_lines(10, 5, &['.', '#'])
+        ) {
+            proptest::prop_assert!((1..=5).contains(&grid.len()));
+
+            let width = grid[0].len();
+            proptest::prop_assert!((1..=10).contains(&width));
+            for line in &grid {
+                proptest::prop_assert_eq!(line.len(), width);
+                proptest::prop_assert!(line.chars().all(|c| c == '.' || c == '#'));
+            }
+        }
+    }
 }
@@ -0,0 +1,58 @@
+//! Progress bars for the long phases of a solution, like brute-force searches. They are only shown
+//! when running a day with `--progress`, so tests and regular runs stay quiet. Available with the
+//! `progress` feature.
+
+use indicatif::{ProgressBar, ProgressStyle};
+
+/// Check whether progress bars were requested on the command line with `--progress`.
+pub fn progress_flag() -> bool {
+    parse_progress_flag(std::env::args().skip(1))
+}
+
+fn parse_progress_flag(args: impl IntoIterator<Item = String>) -> bool {
+    args.into_iter().any(|arg| arg == "--progress")
+}
+
+/// Create a progress bar over `len` items of the given unit, drawn on stderr. The bar is hidden
+/// unless `--progress` was given.
+pub fn progress_bar(len: u64, unit: &str) -> ProgressBar {
+    if !progress_flag() {
+        return ProgressBar::hidden();
+    }
+
+    let template = format!(
+        "[{{elapsed_precise}}] {{bar:40.cyan/blue}} {{pos}}/{{len}} {} ({{eta}})",
+        unit
+    );
+    let style = ProgressStyle::with_template(&template)
+        .expect("Invalid progress bar template")
+        .progress_chars("=> ");
+
+    ProgressBar::new(len).with_style(style)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(&[], false)]
+    #[case(&["--trace"], false)]
+    #[case(&["--trace", "--progress"], true)]
+    fn test_parse_progress_flag(#[case] args: &[&str], #[case] expected: bool) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_progress_flag(args), expected);
+    }
+
+    #[rstest]
+    fn test_progress_bar_hidden_by_default() {
+        let bar = progress_bar(10, "items");
+        bar.inc(3);
+
+        assert!(bar.is_hidden());
+        assert_eq!(bar.position(), 3);
+    }
+}
@@ -0,0 +1,583 @@
+//! Terminal visualization of grid simulations. A day draws its state into a [`Frame`], which a
+//! [`Renderer`] prints with ANSI colors at a steady frame rate. Visualizations are requested by
+//! running a day with `--visualize`, or `--visualize=FPS` to change the frame rate. With the `gif`
+//! feature, `--gif=PATH` saves them as an animated GIF instead.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::thread::sleep;
+use std::time::{Duration, Instant};
+
+/// The frame rate used when `--visualize` is given without one.
+pub const DEFAULT_FPS: u32 = 10;
+
+/// Get the frame rate requested on the command line with `--visualize[=FPS]`, if any.
+pub fn visualize_flag() -> Option<u32> {
+    parse_visualize_flag(std::env::args().skip(1))
+}
+
+fn parse_visualize_flag(args: impl IntoIterator<Item = String>) -> Option<u32> {
+    args.into_iter().find_map(|arg| match arg.as_str() {
+        "--visualize" => Some(DEFAULT_FPS),
+        _ => arg
+            .strip_prefix("--visualize=")
+            .map(|fps| match fps.parse() {
+                Ok(fps) if fps > 0 => fps,
+                _ => panic!("Invalid frame rate: {}", fps),
+            }),
+    })
+}
+
+/// Get the path of the GIF requested on the command line with `--gif=PATH`, if any.
+pub fn gif_flag() -> Option<PathBuf> {
+    parse_gif_flag(std::env::args().skip(1))
+}
+
+fn parse_gif_flag(args: impl IntoIterator<Item = String>) -> Option<PathBuf> {
+    args.into_iter()
+        .find_map(|arg| arg.strip_prefix("--gif=").map(PathBuf::from))
+}
+
+/// Where the frames of a visualization go, as requested on the command line.
+pub enum Visualizer {
+    Terminal(Renderer<io::Stdout>),
+    #[cfg(feature = "gif")]
+    Gif(GifWriter<io::BufWriter<std::fs::File>>),
+}
+
+impl Visualizer {
+    /// Create the visualizer requested with `--gif=PATH` or `--visualize[=FPS]`, if any. The GIF
+    /// takes precedence, and uses the frame rate of `--visualize` if given.
+    pub fn from_args() -> Option<Self> {
+        let fps = visualize_flag();
+
+        if let Some(path) = gif_flag() {
+            return Some(Self::gif(path, fps.unwrap_or(DEFAULT_FPS)));
+        }
+
+        fps.map(|fps| Visualizer::Terminal(Renderer::stdout(fps)))
+    }
+
+    #[cfg(feature = "gif")]
+    fn gif(path: PathBuf, fps: u32) -> Self {
+        let file = std::fs::File::create(&path)
+            .unwrap_or_else(|e| panic!("Unable to create {}: {}", path.display(), e));
+
+        Visualizer::Gif(GifWriter::new(io::BufWriter::new(file), 4, fps))
+    }
+
+    #[cfg(not(feature = "gif"))]
+    fn gif(_path: PathBuf, _fps: u32) -> Self {
+        panic!("GIF export needs the gif feature of aoc-common");
+    }
+
+    /// Draw a frame, with an optional caption which is only shown on the terminal.
+    pub fn draw(&mut self, frame: &Frame, caption: Option<&str>) -> io::Result<()> {
+        match self {
+            Visualizer::Terminal(renderer) => renderer.draw(frame, caption),
+            #[cfg(feature = "gif")]
+            Visualizer::Gif(writer) => writer.write(frame),
+        }
+    }
+
+    /// Finish the visualization, writing out what remains of it.
+    pub fn finish(self) -> io::Result<()> {
+        match self {
+            Visualizer::Terminal(_) => Ok(()),
+            #[cfg(feature = "gif")]
+            Visualizer::Gif(writer) => writer.into_inner()?.flush(),
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Color {
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    Gray,
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// The ANSI escape sequence setting this color as the foreground color.
+    fn ansi(&self) -> String {
+        match *self {
+            Color::Red => String::from("\x1b[31m"),
+            Color::Green => String::from("\x1b[32m"),
+            Color::Yellow => String::from("\x1b[33m"),
+            Color::Blue => String::from("\x1b[34m"),
+            Color::Magenta => String::from("\x1b[35m"),
+            Color::Cyan => String::from("\x1b[36m"),
+            Color::White => String::from("\x1b[37m"),
+            Color::Gray => String::from("\x1b[90m"),
+            Color::Rgb(r, g, b) => format!("\x1b[38;2;{};{};{}m", r, g, b),
+        }
+    }
+
+    /// The RGB value of this color, as used outside of terminals.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match *self {
+            Color::Red => (205, 49, 49),
+            Color::Green => (13, 188, 121),
+            Color::Yellow => (229, 229, 16),
+            Color::Blue => (36, 114, 200),
+            Color::Magenta => (188, 63, 188),
+            Color::Cyan => (17, 168, 205),
+            Color::White => (229, 229, 229),
+            Color::Gray => (118, 118, 118),
+            Color::Rgb(r, g, b) => (r, g, b),
+        }
+    }
+
+    /// The RGB value of this color in hexadecimal, e.g. `#cd3131`, as used in SVG and Graphviz.
+    pub fn hex(&self) -> String {
+        let (r, g, b) = self.rgb();
+
+        format!("#{:02x}{:02x}{:02x}", r, g, b)
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Cell {
+    pub value: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    /// The RGB value of the cell when drawn as a block of pixels. Uncolored cells are dark when
+    /// empty (`.` or a space) and light otherwise.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match (self.color, self.value) {
+            (Some(color), _) => color.rgb(),
+            (None, '.' | ' ') => (24, 24, 24),
+            (None, _) => (200, 200, 200),
+        }
+    }
+}
+
+/// A grid of colored characters, drawn once per step of a simulation.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Frame {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    /// Create a frame filled with uncolored `fill` characters.
+    pub fn new(width: usize, height: usize, fill: char) -> Self {
+        let cell = Cell {
+            value: fill,
+            color: None,
+        };
+
+        Self {
+            width,
+            height,
+            cells: vec![cell; width * height],
+        }
+    }
+
+    /// Create an uncolored frame from the lines of a puzzle input.
+    pub fn from_lines(lines: &[String]) -> Self {
+        let width = lines.iter().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut frame = Self::new(width, lines.len(), ' ');
+
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                frame.set(x, y, c, None);
+            }
+        }
+
+        frame
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Cell {
+        self.cells[self.index(x, y)]
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, value: char, color: Option<Color>) {
+        let idx = self.index(x, y);
+        self.cells[idx] = Cell { value, color };
+    }
+
+    /// Change the color of a cell, keeping its character.
+    pub fn paint(&mut self, x: usize, y: usize, color: Color) {
+        let idx = self.index(x, y);
+        self.cells[idx].color = Some(color);
+    }
+
+    fn index(&self, x: usize, y: usize) -> usize {
+        assert!(
+            x < self.width && y < self.height,
+            "Cell ({}, {}) is outside of the {}x{} frame",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+
+        y * self.width + x
+    }
+
+    /// Render the frame as lines of text, with ANSI escape sequences for the colored cells. The
+    /// color is only changed between cells that differ, and reset at the end of each line.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        for row in self.cells.chunks(self.width.max(1)).take(self.height) {
+            let mut current = None;
+
+            for cell in row {
+                if cell.color != current {
+                    match cell.color {
+                        Some(color) => out.push_str(&color.ansi()),
+                        None => out.push_str(RESET),
+                    }
+                    current = cell.color;
+                }
+                out.push(cell.value);
+            }
+
+            if current.is_some() {
+                out.push_str(RESET);
+            }
+            out.push('\n');
+        }
+
+        out
+    }
+
+    /// Render the frame as plain text, without colors.
+    pub fn render_plain(&self) -> String {
+        let mut out = String::new();
+
+        for row in self.cells.chunks(self.width.max(1)).take(self.height) {
+            let _ = writeln!(out, "{}", row.iter().map(|c| c.value).collect::<String>());
+        }
+
+        out
+    }
+}
+
+/// Draws frames over each other on a terminal, waiting between them to keep the frame rate.
+pub struct Renderer<W: Write> {
+    out: W,
+    frame_duration: Duration,
+    last_frame: Option<Instant>,
+}
+
+impl Renderer<io::Stdout> {
+    /// Create a renderer drawing on the standard output.
+    pub fn stdout(fps: u32) -> Self {
+        Self::new(io::stdout(), fps)
+    }
+}
+
+impl<W: Write> Renderer<W> {
+    pub fn new(out: W, fps: u32) -> Self {
+        assert!(fps > 0, "The frame rate must be positive");
+
+        Self {
+            out,
+            frame_duration: Duration::from_secs(1) / fps,
+            last_frame: None,
+        }
+    }
+
+    /// Draw a frame, with an optional caption below it, once the previous one has been shown
+    /// long enough.
+    pub fn draw(&mut self, frame: &Frame, caption: Option<&str>) -> io::Result<()> {
+        if let Some(last) = self.last_frame {
+            let elapsed = last.elapsed();
+            if elapsed < self.frame_duration {
+                sleep(self.frame_duration - elapsed);
+            }
+        }
+
+        // Move the cursor to the top left corner and clear the screen.
+        self.out.write_all(b"\x1b[H\x1b[2J")?;
+        self.out.write_all(frame.render().as_bytes())?;
+        if let Some(caption) = caption {
+            writeln!(self.out, "{}", caption)?;
+        }
+        self.out.flush()?;
+
+        self.last_frame = Some(Instant::now());
+
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+/// Encodes frames into an animated GIF, drawing each cell as a square block of pixels. The size
+/// of the animation is set by its first frame.
+#[cfg(feature = "gif")]
+pub struct GifWriter<W: Write> {
+    out: Option<W>,
+    encoder: Option<gif::Encoder<W>>,
+    cell_size: u16,
+    delay: u16,
+}
+
+#[cfg(feature = "gif")]
+impl<W: Write> GifWriter<W> {
+    pub fn new(out: W, cell_size: u16, fps: u32) -> Self {
+        assert!(fps > 0, "The frame rate must be positive");
+        assert!(cell_size > 0, "The cell size must be positive");
+
+        Self {
+            out: Some(out),
+            encoder: None,
+            cell_size,
+            // GIF delays are in hundredths of a second, and most viewers don't go below 2.
+            delay: (100 / fps).max(2) as u16,
+        }
+    }
+
+    pub fn write(&mut self, frame: &Frame) -> io::Result<()> {
+        let size = self.cell_size as usize;
+        let (width, height) = (frame.width() * size, frame.height() * size);
+        let (width, height) = (
+            u16::try_from(width).map_err(io::Error::other)?,
+            u16::try_from(height).map_err(io::Error::other)?,
+        );
+
+        let encoder = match &mut self.encoder {
+            Some(encoder) => encoder,
+            None => {
+                let out = self.out.take().expect("The writer has been used");
+                let mut encoder =
+                    gif::Encoder::new(out, width, height, &[]).map_err(io::Error::other)?;
+                encoder
+                    .set_repeat(gif::Repeat::Infinite)
+                    .map_err(io::Error::other)?;
+                self.encoder.insert(encoder)
+            }
+        };
+
+        let mut rgb = Vec::with_capacity(width as usize * height as usize * 3);
+        for y in 0..frame.height() {
+            let row: Vec<u8> = (0..frame.width())
+                .flat_map(|x| {
+                    let (r, g, b) = frame.get(x, y).rgb();
+                    [r, g, b].repeat(size)
+                })
+                .collect();
+
+            for _ in 0..size {
+                rgb.extend_from_slice(&row);
+            }
+        }
+
+        // Frames are drawn with an exact palette of their own colors when they fit in one, which
+        // is the usual case. Otherwise, the encoder quantizes them.
+        let mut palette: Vec<u8> = Vec::new();
+        let mut indices: std::collections::HashMap<&[u8], u8> = Default::default();
+        let mut pixels = Vec::with_capacity(width as usize * height as usize);
+        for color in rgb.chunks(3) {
+            let idx = match indices.get(color) {
+                Some(&idx) => idx,
+                None if indices.len() < 256 => {
+                    let idx = indices.len() as u8;
+                    indices.insert(color, idx);
+                    palette.extend_from_slice(color);
+                    idx
+                }
+                None => break,
+            };
+            pixels.push(idx);
+        }
+
+        let mut gif_frame = if pixels.len() * 3 == rgb.len() {
+            gif::Frame::from_palette_pixels(width, height, pixels, palette, None)
+        } else {
+            gif::Frame::from_rgb_speed(width, height, &rgb, 10)
+        };
+        gif_frame.delay = self.delay;
+
+        encoder.write_frame(&gif_frame).map_err(io::Error::other)
+    }
+
+    /// Finish the animation and get the underlying writer back.
+    pub fn into_inner(self) -> io::Result<W> {
+        match self.encoder {
+            Some(encoder) => encoder.into_inner(),
+            None => Ok(self.out.expect("The writer has been used")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::parse_test_input;
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--trace"], None)]
+    #[case(&["--visualize"], Some(DEFAULT_FPS))]
+    #[case(&["--trace", "--visualize=30"], Some(30))]
+    fn test_parse_visualize_flag(#[case] args: &[&str], #[case] expected: Option<u32>) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_visualize_flag(args), expected);
+    }
+
+    #[rstest]
+    #[case("--visualize=0")]
+    #[case("--visualize=fast")]
+    #[should_panic(expected = "Invalid frame rate")]
+    fn test_parse_visualize_flag_invalid(#[case] arg: &str) {
+        parse_visualize_flag([arg.to_string()]);
+    }
+
+    #[rstest]
+    #[case(&[], None)]
+    #[case(&["--visualize"], None)]
+    #[case(&["--visualize", "--gif=out/day14.gif"], Some("out/day14.gif"))]
+    fn test_parse_gif_flag(#[case] args: &[&str], #[case] expected: Option<&str>) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_gif_flag(args), expected.map(PathBuf::from));
+    }
+
+    #[rstest]
+    #[case('#', None, (200, 200, 200))]
+    #[case('.', None, (24, 24, 24))]
+    #[case('.', Some(Color::Red), (205, 49, 49))]
+    #[case('O', Some(Color::Rgb(1, 2, 3)), (1, 2, 3))]
+    fn test_cell_rgb(
+        #[case] value: char,
+        #[case] color: Option<Color>,
+        #[case] expected: (u8, u8, u8),
+    ) {
+        assert_eq!(Cell { value, color }.rgb(), expected);
+    }
+
+    #[rstest]
+    #[case(Color::Red, "#cd3131")]
+    #[case(Color::Rgb(0, 10, 255), "#000aff")]
+    fn test_color_hex(#[case] color: Color, #[case] expected: &str) {
+        assert_eq!(color.hex(), expected);
+    }
+
+    #[rstest]
+    fn test_render() {
+        let mut frame = Frame::from_lines(&parse_test_input(
+            "
+            O.#
+            .O.
+            ",
+        ));
+        frame.paint(0, 0, Color::Yellow);
+        frame.paint(2, 0, Color::Rgb(1, 2, 3));
+        frame.set(1, 1, '@', Some(Color::Yellow));
+
+        assert_eq!(
+            frame.render(),
+            "\x1b[33mO\x1b[0m.\x1b[38;2;1;2;3m#\x1b[0m\n.\x1b[33m@\x1b[0m.\n"
+        );
+        assert_eq!(frame.render_plain(), "O.#\n.@.\n");
+    }
+
+    #[rstest]
+    fn test_renderer_keeps_frame_rate() {
+        let frame = Frame::new(2, 1, '.');
+        let mut renderer = Renderer::new(Vec::new(), 20);
+
+        let start = Instant::now();
+        for _ in 0..3 {
+            renderer.draw(&frame, Some("step")).unwrap();
+        }
+
+        assert!(start.elapsed() >= Duration::from_millis(100));
+        assert_eq!(
+            String::from_utf8(renderer.into_inner()).unwrap(),
+            "\x1b[H\x1b[2J..\nstep\n".repeat(3)
+        );
+    }
+
+    #[cfg(feature = "gif")]
+    #[rstest]
+    fn test_gif_writer() {
+        let mut first = Frame::new(3, 2, '.');
+        first.set(2, 1, 'O', Some(Color::Rgb(255, 0, 0)));
+        let mut second = first.clone();
+        second.set(0, 0, '#', None);
+
+        let mut writer = GifWriter::new(Vec::new(), 2, 25);
+        writer.write(&first).unwrap();
+        writer.write(&second).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = options.read_info(data.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (6, 4));
+
+        let pixel = |frame: &gif::Frame, x: usize, y: usize| {
+            let idx = (y * frame.width as usize + x) * 4;
+            (
+                frame.buffer[idx],
+                frame.buffer[idx + 1],
+                frame.buffer[idx + 2],
+            )
+        };
+
+        let frame = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(frame.delay, 4);
+        assert_eq!(pixel(frame, 0, 0), (24, 24, 24));
+        assert_eq!(pixel(frame, 5, 3), (255, 0, 0));
+        assert_eq!(pixel(frame, 4, 2), (255, 0, 0));
+
+        let frame = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!(pixel(frame, 1, 1), (200, 200, 200));
+        assert_eq!(pixel(frame, 2, 1), (24, 24, 24));
+
+        assert!(decoder.read_next_frame().unwrap().is_none());
+    }
+
+    #[cfg(feature = "gif")]
+    #[rstest]
+    fn test_gif_writer_with_many_colors() {
+        let mut frame = Frame::new(20, 20, '.');
+        for y in 0..20 {
+            for x in 0..20 {
+                let color = Color::Rgb(x as u8 * 12, y as u8 * 12, 128);
+                frame.set(x, y, '#', Some(color));
+            }
+        }
+
+        let mut writer = GifWriter::new(Vec::new(), 1, 10);
+        writer.write(&frame).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut decoder = gif::DecodeOptions::new()
+            .read_info(data.as_slice())
+            .unwrap();
+        let frame = decoder.read_next_frame().unwrap().unwrap();
+        assert_eq!((frame.width, frame.height), (20, 20));
+    }
+}
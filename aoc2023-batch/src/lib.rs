@@ -0,0 +1,208 @@
+//! Solve every input of a directory for the same day, e.g. the whole family's inputs, in
+//! parallel and print their answers as a table.
+//!
+//! ```text
+//! cargo run --release -p aoc2023-batch -- 7 inputs/day07
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use rayon::prelude::*;
+
+use aoc2023_solutions::{solve_day, Answer};
+use aoc_common::format_duration;
+
+/// Solve the inputs of the directory given on the command line and print their answers, as the
+/// `aoc2023-batch` binary does.
+pub fn run() {
+    let (day, dir) = parse_args(std::env::args().skip(1));
+
+    let start = Instant::now();
+
+    let results = solve_batch(day, &dir)
+        .unwrap_or_else(|e| panic!("Unable to read directory {}: {}", dir.display(), e));
+
+    let t = start.elapsed().as_nanos();
+
+    print!("{}", render_table(&results));
+    println!("Duration: {}", format_duration(t));
+}
+
+/// The answers to an input of the batch, or why it couldn't be solved.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BatchResult {
+    /// The file name of the input.
+    pub input: String,
+    pub answers: Result<(Answer, Answer), String>,
+}
+
+/// Solve every file of `dir` as an input of `day`, in parallel. The results are sorted by file
+/// name. An input that can't be read or solved gets an error, without stopping the others.
+pub fn solve_batch(day: u8, dir: &Path) -> io::Result<Vec<BatchResult>> {
+    let mut paths = fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    paths.retain(|p| p.is_file());
+    paths.sort();
+
+    Ok(paths.par_iter().map(|p| solve_input(day, p)).collect())
+}
+
+fn solve_input(day: u8, path: &Path) -> BatchResult {
+    let answers = fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|input| solve_day(day, &input).map_err(|e| e.to_string()));
+
+    BatchResult {
+        input: path.file_name().unwrap().to_string_lossy().into_owned(),
+        answers,
+    }
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> (u8, PathBuf) {
+    let args: Vec<String> = args.into_iter().collect();
+
+    let [day, dir] = args.as_slice() else {
+        panic!("Usage: aoc2023-batch DAY DIR");
+    };
+
+    let day = day
+        .parse()
+        .unwrap_or_else(|_| panic!("Invalid day: {}", day));
+
+    (day, PathBuf::from(dir))
+}
+
+/// Draw the answers of each input as a table, with the error instead of the answers for the
+/// inputs that couldn't be solved.
+fn render_table(results: &[BatchResult]) -> String {
+    let width = |header: &str, cell: &dyn Fn(&BatchResult) -> Option<usize>| {
+        results
+            .iter()
+            .filter_map(cell)
+            .fold(header.len(), usize::max)
+    };
+
+    let input_width = width("Input", &|r| Some(r.input.len()));
+    let p1_width = width("Part 1", &|r| {
+        r.answers.as_ref().ok().map(|a| a.0.as_str().len())
+    });
+
+    let mut table = format!(
+        "{:input_width$}  {:p1_width$}  {}\n",
+        "Input", "Part 1", "Part 2"
+    );
+
+    for result in results {
+        let row = match &result.answers {
+            Ok((p1, p2)) => format!(
+                "{:input_width$}  {:p1_width$}  {}",
+                result.input,
+                p1.as_str(),
+                p2
+            ),
+            Err(e) => format!("{:input_width$}  error: {}", result.input, e),
+        };
+
+        table.push_str(row.trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use tempfile::TempDir;
+
+    use super::*;
+
+    fn write_inputs(inputs: &[(&str, &str)]) -> TempDir {
+        let dir = TempDir::new().unwrap();
+
+        for (name, content) in inputs {
+            fs::write(dir.path().join(name), content).unwrap();
+        }
+
+        dir
+    }
+
+    #[rstest]
+    fn test_solve_batch() {
+        let dir = write_inputs(&[
+            ("bob.txt", "a1b\n7x\n"),
+            ("alice.txt", "1abc2\npqr3stu8vwx\n"),
+            ("carol.txt", "abc\n"),
+        ]);
+        fs::create_dir(dir.path().join("archive")).unwrap();
+
+        let results = solve_batch(1, dir.path()).unwrap();
+
+        let inputs: Vec<_> = results.iter().map(|r| r.input.as_str()).collect();
+        assert_eq!(inputs, vec!["alice.txt", "bob.txt", "carol.txt"]);
+
+        let answers: Vec<_> = results
+            .iter()
+            .map(|r| {
+                r.answers
+                    .as_ref()
+                    .ok()
+                    .map(|(p1, p2)| (p1.as_str(), p2.as_str()))
+            })
+            .collect();
+        assert_eq!(answers, vec![Some(("50", "50")), Some(("88", "88")), None]);
+    }
+
+    #[rstest]
+    fn test_solve_batch_missing_directory() {
+        let dir = TempDir::new().unwrap();
+
+        let err = solve_batch(1, &dir.path().join("missing")).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[rstest]
+    fn test_render_table() {
+        let dir = write_inputs(&[
+            ("alice.txt", "1abc2\npqr3stu8vwx\n"),
+            ("b.txt", "two1nine\n"),
+        ]);
+        let mut results = solve_batch(1, dir.path()).unwrap();
+        results.push(BatchResult {
+            input: "carol.txt".to_string(),
+            answers: Err("invalid input".to_string()),
+        });
+
+        let expected = "\
+Input      Part 1  Part 2
+alice.txt  50      50
+b.txt      11      29
+carol.txt  error: invalid input
+";
+        assert_eq!(render_table(&results), expected);
+    }
+
+    #[rstest]
+    fn test_parse_args() {
+        let args = ["7", "inputs/day07"].map(String::from);
+
+        assert_eq!(parse_args(args), (7, PathBuf::from("inputs/day07")));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Usage: aoc2023-batch DAY DIR")]
+    fn test_parse_args_missing_dir() {
+        parse_args(["7".to_string()]);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid day: seven")]
+    fn test_parse_args_invalid_day() {
+        parse_args(["seven", "inputs/day07"].map(String::from));
+    }
+}
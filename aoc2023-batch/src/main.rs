@@ -0,0 +1,3 @@
+fn main() {
+    aoc2023_batch::run();
+}
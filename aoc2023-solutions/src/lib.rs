@@ -0,0 +1,131 @@
+//! The solutions of every solved day behind a single function, [`solve_day`], for the tools that
+//! need to run any day from its input rather than depend on each day's crate.
+
+use std::fmt::{self, Display};
+
+pub use aoc_common::ParseError;
+
+/// The answer to a part of a puzzle, as printed by the day's binary.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Answer(String);
+
+impl Answer {
+    fn new(answer: impl Display) -> Self {
+        Self(answer.to_string())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum Error {
+    /// The day isn't one of the 25 days of the event.
+    UnknownDay(u8),
+    /// The day's puzzle isn't solved yet.
+    Unsolved(u8),
+    /// The input couldn't be parsed by the day's solution.
+    Parse(ParseError),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnknownDay(day) => write!(f, "unknown day {}", day),
+            Error::Unsolved(day) => write!(f, "day {} isn't solved yet", day),
+            Error::Parse(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Self {
+        Error::Parse(error)
+    }
+}
+
+fn answers((p1, p2): (impl Display, impl Display)) -> (Answer, Answer) {
+    (Answer::new(p1), Answer::new(p2))
+}
+
+/// Solve both parts of a day's puzzle from its input, with the same answers as the day's binary
+/// when run without options.
+pub fn solve_day(day: u8, input: &str) -> Result<(Answer, Answer), Error> {
+    let lines: Vec<String> = input.lines().map(String::from).collect();
+
+    let answers = match day {
+        1 => day01::solve(&lines).map(answers),
+        2 => day02::solve(&lines).map(answers),
+        3 => day03::solve(&lines).map(answers),
+        4 => day04::solve(&lines).map(answers),
+        5 => day05::solve(&lines).map(answers),
+        6 => day06::solve(&lines).map(answers),
+        7 => day07::solve(&lines).map(answers),
+        8 => day08::solve(&lines).map(answers),
+        9 => day09::solve(&lines).map(answers),
+        10 => day10::solve(&lines).map(answers),
+        11 => day11::solve(&lines).map(answers),
+        13 => day13::solve(&lines).map(answers),
+        14 => day14::solve(&lines).map(answers),
+        15 => day15::solve(input.trim()).map(answers),
+        16 => day16::solve(&lines).map(answers),
+        18 => day18::solve(&lines).map(answers),
+        19 => day19::solve(&lines).map(answers),
+        12 | 17 | 20..=25 => return Err(Error::Unsolved(day)),
+        _ => return Err(Error::UnknownDay(day)),
+    };
+
+    Ok(answers?)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use aoc_common::{get_examples, get_input_as_string};
+
+    use super::*;
+
+    #[rstest]
+    fn test_solve_day_examples(
+        #[values(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 14, 15, 16, 18, 19)] day: u8,
+    ) {
+        let examples: Vec<_> = get_examples(&format!("day{:02}", day))
+            .into_iter()
+            .filter(|e| e.part1.is_some() && e.part2.is_some())
+            .collect();
+        assert!(!examples.is_empty(), "No example of day {}", day);
+
+        for example in examples {
+            let (p1, p2) = solve_day(day, &get_input_as_string(&example.file)).unwrap();
+
+            assert_eq!(Some(p1.as_str()), example.part1.as_deref());
+            assert_eq!(Some(p2.as_str()), example.part2.as_deref());
+        }
+    }
+
+    #[rstest]
+    #[case(12, Error::Unsolved(12))]
+    #[case(25, Error::Unsolved(25))]
+    #[case(0, Error::UnknownDay(0))]
+    #[case(26, Error::UnknownDay(26))]
+    fn test_solve_day_unavailable(#[case] day: u8, #[case] expected: Error) {
+        assert_eq!(solve_day(day, "").unwrap_err(), expected);
+    }
+
+    #[rstest]
+    fn test_solve_day_invalid_input() {
+        let err = solve_day(4, "Card 1: 41 48 | 83 x6").unwrap_err();
+
+        assert!(matches!(err, Error::Parse(ParseError { day: "day04", .. })));
+    }
+}
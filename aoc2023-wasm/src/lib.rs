@@ -0,0 +1,93 @@
+//! The solutions compiled to WebAssembly, so they can run in a browser or be published as an npm
+//! package. Build it with `wasm-pack build aoc2023-wasm`, or with
+//! `cargo build -p aoc2023-wasm --target wasm32-unknown-unknown` and `wasm-bindgen`.
+
+use js_sys::{Object, Reflect};
+use wasm_bindgen::prelude::*;
+
+use aoc2023_solutions::solve_day;
+
+/// What [`solve`] hands back to JavaScript.
+#[derive(Debug, Eq, PartialEq)]
+enum Solution {
+    Solved { part1: String, part2: String },
+    Failed { error: String },
+}
+
+impl Solution {
+    fn new(day: u8, input: &str) -> Self {
+        match solve_day(day, input) {
+            Ok((p1, p2)) => Solution::Solved {
+                part1: p1.to_string(),
+                part2: p2.to_string(),
+            },
+            Err(e) => Solution::Failed {
+                error: e.to_string(),
+            },
+        }
+    }
+
+    fn fields(&self) -> Vec<(&'static str, &str)> {
+        match self {
+            Solution::Solved { part1, part2 } => vec![("part1", part1), ("part2", part2)],
+            Solution::Failed { error } => vec![("error", error)],
+        }
+    }
+}
+
+impl From<Solution> for JsValue {
+    fn from(solution: Solution) -> Self {
+        let object = Object::new();
+
+        for (key, value) in solution.fields() {
+            Reflect::set(&object, &key.into(), &value.into())
+                .expect("Setting a property of a plain object can't fail");
+        }
+
+        object.into()
+    }
+}
+
+/// Solve both parts of a day's puzzle from its input. The answers are returned as strings, in an
+/// object `{ part1, part2 }`, or as `{ error }` when the day isn't solved or the input is invalid.
+#[wasm_bindgen]
+pub fn solve(day: u8, input: &str) -> JsValue {
+    Solution::new(day, input).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    fn test_solution_solved() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet";
+        let solution = Solution::new(1, input);
+
+        assert_eq!(
+            solution,
+            Solution::Solved {
+                part1: "142".to_string(),
+                part2: "142".to_string(),
+            }
+        );
+        assert_eq!(solution.fields(), vec![("part1", "142"), ("part2", "142")]);
+    }
+
+    #[rstest]
+    #[case(12, "day 12 isn't solved yet")]
+    #[case(26, "unknown day 26")]
+    fn test_solution_failed(#[case] day: u8, #[case] expected: &str) {
+        let solution = Solution::new(day, "");
+
+        assert_eq!(
+            solution,
+            Solution::Failed {
+                error: expected.to_string()
+            }
+        );
+        assert_eq!(solution.fields(), vec![("error", expected)]);
+    }
+}
@@ -0,0 +1,246 @@
+use aho_corasick::AhoCorasick;
+use itertools::Itertools;
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day01` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day01.txt").read();
+
+    let words = std::env::args()
+        .skip(1)
+        .find_map(|a| a.strip_prefix("--words=").map(parse_words))
+        .unwrap_or_else(|| SPELLED_OUT_DIGITS.map(|(w, v)| (w.to_string(), v)).to_vec());
+    let words = words.iter().map(|(w, v)| (w.as_str(), *v)).collect_vec();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve_with_words(input.as_slice(), &words).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+}
+
+/// Solve both parts, with part 2 matching the usual spelled out digits.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    solve_with_words(input, &SPELLED_OUT_DIGITS)
+}
+
+/// Solve both parts, with part 2 matching the given spelled out digits in addition to the digits.
+fn solve_with_words(
+    input: &[String],
+    words: &[(&str, u32)],
+) -> Result<(impl Display, impl Display), ParseError> {
+    let numbers = extract_first_and_last_digits(input, &[])?;
+    let p1 = get_calibration_value(&numbers);
+    let numbers = extract_first_and_last_digits(input, words)?;
+    let p2 = get_calibration_value(&numbers);
+
+    Ok((p1, p2))
+}
+
+/// Patterns matched as digits, along with their value.
+const DIGITS: [(&str, u32); 9] = [
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// The spelled out digits matched in part 2, unless another table is given with `--words`.
+const SPELLED_OUT_DIGITS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+/// Extract the first and last digits of each line, matching the given spelled out digits along
+/// with the digits themselves.
+fn extract_first_and_last_digits(
+    input: &[String],
+    words: &[(&str, u32)],
+) -> Result<Vec<(u32, u32)>, ParseError> {
+    let mut patterns = DIGITS.to_vec();
+    patterns.extend(words);
+
+    let matcher = AhoCorasick::new(patterns.iter().map(|(p, _)| p)).expect("Invalid patterns");
+
+    parse_lines("day01", input, |entry| {
+        // Overlapping matches are needed since spelled out digits can share letters, e.g.
+        // `eightwo` ends with a two.
+        let mut matches = matcher.find_overlapping_iter(entry);
+
+        let first = matches.next().ok_or("no digit found")?;
+        let last = matches.last().unwrap_or(first);
+
+        Ok((patterns[first.pattern()].1, patterns[last.pattern()].1))
+    })
+}
+
+fn get_calibration_value(entries: &[(u32, u32)]) -> u32 {
+    entries.iter().map(|e| e.0 * 10 + e.1).sum()
+}
+
+/// Parse a table of spelled out digits given as `WORD:VALUE,...`, e.g. `zero:0,one:1`.
+fn parse_words(value: &str) -> Vec<(String, u32)> {
+    value
+        .split(',')
+        .map(|entry| {
+            let Some((word, digit)) = entry.split_once(':') else {
+                panic!("Invalid word, expected WORD:VALUE: {}", entry);
+            };
+
+            let digit: u32 = digit
+                .parse()
+                .unwrap_or_else(|_| panic!("Invalid value for {}: {}", word, digit));
+            assert!(digit < 10, "Invalid value for {}: {}", word, digit);
+
+            (word.to_string(), digit)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_test_input;
+
+    use super::*;
+
+    aoc_common::puzzle_fixture!("day01.txt");
+
+    #[test]
+    fn test_p1() {
+        let input = parse_test_input(
+            "
+            1abc2
+            pqr3stu8vwx
+            a1b2c3d4e5f
+            treb7uchet
+            ",
+        );
+
+        let digits = extract_first_and_last_digits(&input, &[]).unwrap();
+        let res = get_calibration_value(&digits);
+
+        assert_eq!(res, 142);
+    }
+
+    #[test]
+    fn test_p2() {
+        let input = parse_test_input(
+            "
+            two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen
+            ",
+        );
+
+        let digits = extract_first_and_last_digits(&input, &SPELLED_OUT_DIGITS).unwrap();
+        let res = get_calibration_value(&digits);
+
+        assert_eq!(res, 281);
+    }
+
+    #[test]
+    fn test_overlapping_spelled_out_digits() {
+        let input = parse_test_input(
+            "
+            oneight
+            3twone
+            ",
+        );
+
+        let digits = extract_first_and_last_digits(&input, &SPELLED_OUT_DIGITS).unwrap();
+
+        assert_eq!(digits, vec![(1, 8), (3, 1)]);
+    }
+
+    #[test]
+    fn test_line_without_digit() {
+        let input = parse_test_input(
+            "
+            1abc2
+            pqrstu
+            ",
+        );
+
+        let err = extract_first_and_last_digits(&input, &[]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "day01 line 2: no digit found in \"pqrstu\""
+        );
+    }
+
+    #[test]
+    fn test_custom_words() {
+        let input = parse_test_input(
+            "
+            zerotwo
+            un2trois
+            zeroneight
+            ",
+        );
+        let words = [("zero", 0), ("un", 1), ("trois", 3)];
+
+        let digits = extract_first_and_last_digits(&input, &words).unwrap();
+
+        assert_eq!(digits, vec![(0, 0), (1, 3), (0, 0)]);
+    }
+
+    #[test]
+    fn test_parse_words() {
+        assert_eq!(
+            parse_words("zero:0,un:1"),
+            vec![(String::from("zero"), 0), (String::from("un"), 1)]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid word, expected WORD:VALUE: deux")]
+    fn test_parse_words_without_value() {
+        parse_words("un:1,deux");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid value for dix: 10")]
+    fn test_parse_words_with_invalid_value() {
+        parse_words("dix:10");
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let digits = extract_first_and_last_digits(&input, &[]).unwrap();
+        get_calibration_value(&digits)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let digits = extract_first_and_last_digits(&input, &SPELLED_OUT_DIGITS).unwrap();
+        get_calibration_value(&digits)
+    }
+
+    aoc_common::answer_tests!("day01", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day01", part1, part2);
+
+    aoc_common::perf_test!("day01", 5, puzzle_input(), solve);
+}
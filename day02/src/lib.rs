@@ -0,0 +1,422 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day02` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day02.txt").read();
+    let bag = parse_bag(std::env::args().skip(1));
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve_with_bag(input.as_slice(), &bag).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+}
+
+/// The cubes in the bag for part 1, unless other limits are given with `--red`, `--green` or
+/// `--blue`.
+const BAG: GameSet = GameSet {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+/// Solve both parts, with the cubes of `BAG` in the bag for part 1.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    solve_with_bag(input, &BAG)
+}
+
+/// Solve both parts, with the given cubes in the bag for part 1.
+pub fn solve_with_bag(
+    input: &[String],
+    bag: &GameSet,
+) -> Result<(impl Display, impl Display), ParseError> {
+    let games = parse_games(input)?;
+
+    let p1: u32 = get_possible_games(&games, bag).iter().sum();
+    let p2: u32 = get_power_of_sets(&games).iter().sum();
+
+    Ok((p1, p2))
+}
+
+/// A game, with the sets of cubes revealed during it. A parsed game has at least one set.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Game {
+    pub id: u32,
+    pub sets: Vec<GameSet>,
+}
+
+impl Game {
+    /// The fewest cubes of each color needed for all of the game's sets to be possible.
+    pub fn minimum_set(&self) -> GameSet {
+        let red = self.sets.iter().map(|s| s.red).max().unwrap();
+        let green = self.sets.iter().map(|s| s.green).max().unwrap();
+        let blue = self.sets.iter().map(|s| s.blue).max().unwrap();
+
+        GameSet { red, green, blue }
+    }
+}
+
+/// A number of cubes of each color, either revealed during a game or held in the bag.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub struct GameSet {
+    pub red: u32,
+    pub green: u32,
+    pub blue: u32,
+}
+
+impl GameSet {
+    /// Check whether this set can be drawn from a bag holding the given cubes.
+    pub fn fits_in(&self, bag: &GameSet) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+}
+
+/// Minimal cursor over the bytes of a game entry.
+struct Scanner<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(entry: &'a str) -> Self {
+        Self {
+            bytes: entry.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let c = self.bytes.get(self.pos).copied();
+        self.pos += 1;
+
+        c
+    }
+
+    fn eat(&mut self, expected: &[u8]) -> bool {
+        if self.bytes[self.pos..].starts_with(expected) {
+            self.pos += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: &[u8]) -> Result<(), String> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            Err(format!(
+                "expected \"{}\" at column {}",
+                String::from_utf8_lossy(expected),
+                self.pos + 1
+            ))
+        }
+    }
+
+    fn number(&mut self) -> Result<u32, String> {
+        let start = self.pos;
+        let mut n: u32 = 0;
+
+        while let Some(c) = self.bytes.get(self.pos).filter(|c| c.is_ascii_digit()) {
+            n = n
+                .checked_mul(10)
+                .and_then(|n| n.checked_add((c - b'0') as u32))
+                .ok_or_else(|| format!("number too large at column {}", start + 1))?;
+            self.pos += 1;
+        }
+
+        if self.pos == start {
+            return Err(format!("expected a number at column {}", start + 1));
+        }
+
+        Ok(n)
+    }
+}
+
+/// Parse the games, one per line.
+pub fn parse_games(input: &[String]) -> Result<Vec<Game>, ParseError> {
+    parse_lines("day02", input, parse_game)
+}
+
+fn parse_game(entry: &str) -> Result<Game, String> {
+    let mut scanner = Scanner::new(entry);
+
+    scanner.expect(b"Game ")?;
+    let id = scanner.number()?;
+    scanner.expect(b": ")?;
+
+    let mut sets = Vec::new();
+    let mut game_set = GameSet::default();
+
+    loop {
+        let n = scanner.number()?;
+        scanner.expect(b" ")?;
+
+        if scanner.eat(b"red") {
+            game_set.red = n;
+        } else if scanner.eat(b"green") {
+            game_set.green = n;
+        } else if scanner.eat(b"blue") {
+            game_set.blue = n;
+        } else {
+            return Err(format!("invalid color at column {}", scanner.pos + 1));
+        }
+
+        match scanner.next() {
+            None => {
+                sets.push(game_set);
+                break;
+            }
+            Some(b',') => scanner.expect(b" ")?,
+            Some(b';') => {
+                sets.push(std::mem::take(&mut game_set));
+                scanner.expect(b" ")?;
+            }
+            Some(c) => {
+                return Err(format!(
+                    "unexpected character '{}' at column {}",
+                    c as char, scanner.pos
+                ))
+            }
+        }
+    }
+
+    Ok(Game { id, sets })
+}
+
+fn get_possible_games(games: &[Game], bag: &GameSet) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|g| g.minimum_set().fits_in(bag))
+        .map(|g| g.id)
+        .collect()
+}
+
+fn get_power_of_sets(games: &[Game]) -> Vec<u32> {
+    games
+        .iter()
+        .map(|g| {
+            let minimum_set = g.minimum_set();
+
+            minimum_set.red * minimum_set.green * minimum_set.blue
+        })
+        .collect()
+}
+
+/// Get the bag of part 1 from the command line, with `--red=N`, `--green=N` and `--blue=N`
+/// overriding the default limits.
+fn parse_bag(args: impl IntoIterator<Item = String>) -> GameSet {
+    let mut bag = BAG;
+
+    for arg in args {
+        let Some((color, n)) = arg.strip_prefix("--").and_then(|a| a.split_once('=')) else {
+            continue;
+        };
+
+        let limit = match color {
+            "red" => &mut bag.red,
+            "green" => &mut bag.green,
+            "blue" => &mut bag.blue,
+            _ => continue,
+        };
+        *limit = n
+            .parse()
+            .unwrap_or_else(|_| panic!("Invalid number of {} cubes: {}", color, n));
+    }
+
+    bag
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_test_input;
+    use rstest::*;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 2);
+
+    aoc_common::puzzle_fixture!("day02.txt");
+
+    #[rstest]
+    fn test_parse_games() {
+        let input = parse_test_input(
+            "
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+            Game 42: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            ",
+        );
+
+        let games = parse_games(&input).unwrap();
+        assert_eq!(
+            games,
+            vec![
+                Game {
+                    id: 1,
+                    sets: vec![
+                        GameSet {
+                            red: 4,
+                            green: 0,
+                            blue: 3
+                        },
+                        GameSet {
+                            red: 1,
+                            green: 2,
+                            blue: 6
+                        },
+                        GameSet {
+                            red: 0,
+                            green: 2,
+                            blue: 0
+                        }
+                    ]
+                },
+                Game {
+                    id: 2,
+                    sets: vec![
+                        GameSet {
+                            red: 0,
+                            green: 2,
+                            blue: 1
+                        },
+                        GameSet {
+                            red: 1,
+                            green: 3,
+                            blue: 4
+                        },
+                        GameSet {
+                            red: 0,
+                            green: 1,
+                            blue: 1
+                        }
+                    ]
+                },
+                Game {
+                    id: 42,
+                    sets: vec![
+                        GameSet {
+                            red: 20,
+                            green: 8,
+                            blue: 6
+                        },
+                        GameSet {
+                            red: 4,
+                            green: 13,
+                            blue: 5
+                        },
+                        GameSet {
+                            red: 1,
+                            green: 5,
+                            blue: 0
+                        }
+                    ]
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("Gme 1: 3 blue", "expected \"Game \" at column 1")]
+    #[case("Game x: 3 blue", "expected a number at column 6")]
+    #[case("Game 1 3 blue", "expected \": \" at column 7")]
+    #[case("Game 1: 3 purple", "invalid color at column 11")]
+    #[case("Game 1: 3 blue,4 red", "expected \" \" at column 16")]
+    #[case("Game 1: 3 blue. 4 red", "unexpected character '.' at column 15")]
+    #[case("Game 1: 3 blue; ", "expected a number at column 17")]
+    #[case("Game 99999999999: 3 blue", "number too large at column 6")]
+    fn test_parse_games_invalid_entry(#[case] entry: &str, #[case] expected: &str) {
+        let input = vec![String::from("Game 1: 1 red"), String::from(entry)];
+
+        assert_eq!(
+            parse_games(&input),
+            Err(ParseError::new("day02", 2, entry, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let games = parse_games(&test_input).unwrap();
+        let res: u32 = get_possible_games(&games, &BAG).iter().sum();
+
+        assert_eq!(res, 8);
+    }
+
+    #[rstest]
+    fn test_minimum_set(test_input: Vec<String>) {
+        let games = parse_games(&test_input).unwrap();
+
+        assert_eq!(
+            games[0].minimum_set(),
+            GameSet {
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(BAG, vec![1, 2, 5])]
+    #[case(GameSet { red: 20, green: 13, blue: 15 }, vec![1, 2, 3, 4, 5])]
+    #[case(GameSet { red: 4, green: 3, blue: 6 }, vec![1, 2])]
+    #[case(GameSet::default(), vec![])]
+    fn test_get_possible_games(
+        test_input: Vec<String>,
+        #[case] bag: GameSet,
+        #[case] expected: Vec<u32>,
+    ) {
+        let games = parse_games(&test_input).unwrap();
+
+        assert_eq!(get_possible_games(&games, &bag), expected);
+    }
+
+    #[rstest]
+    #[case(&[], BAG)]
+    #[case(&["--red=1", "--blue=3"], GameSet { red: 1, green: 13, blue: 3 })]
+    #[case(&["--trace", "--green=0"], GameSet { red: 12, green: 0, blue: 14 })]
+    fn test_parse_bag(#[case] args: &[&str], #[case] expected: GameSet) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_bag(args), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid number of red cubes: many")]
+    fn test_parse_bag_invalid_limit() {
+        parse_bag([String::from("--red=many")]);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let games = parse_games(&test_input).unwrap();
+        let res = get_power_of_sets(&games);
+
+        assert_eq!(res, vec![48, 12, 1560, 630, 36]);
+        assert_eq!(res.iter().sum::<u32>(), 2286);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let games = parse_games(&input).unwrap();
+        get_possible_games(&games, &BAG).iter().sum::<u32>()
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let games = parse_games(&input).unwrap();
+        let res = get_power_of_sets(&games);
+        res.iter().sum::<u32>()
+    }
+
+    aoc_common::answer_tests!("day02", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day02", part1, part2);
+
+    aoc_common::perf_test!("day02", 1, puzzle_input(), solve);
+}
@@ -0,0 +1,576 @@
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::viz::{Color, Frame, Visualizer};
+use aoc_common::{format_duration, InputSource, ParseError, Point};
+use regex::Regex;
+
+/// Solve the puzzle input and print the answers, as the `day03` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day03.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if let Some(mut visualizer) = Visualizer::from_args() {
+        let board = parse(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        let caption = format!(
+            "{} valid parts out of {}, {} gears",
+            board.get_valid_parts().len(),
+            board.parts.len(),
+            board.gears().len()
+        );
+        visualizer
+            .draw(&render_schematic(&input, &board), Some(&caption))
+            .expect("Unable to draw the schematic");
+        visualizer.finish().expect("Unable to finish the schematic");
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let board = parse(input)?;
+
+    let p1 = board.get_sum_of_valid_parts();
+    let p2 = board.get_sum_of_gear_ratios();
+
+    Ok((p1, p2))
+}
+
+/// A number of the schematic, from its first to its last digit.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct EnginePart {
+    pub value: u32,
+    pub position: (Point<usize>, Point<usize>),
+}
+
+impl EnginePart {
+    /// Iterate over the cells of the part.
+    pub fn cells(&self) -> impl Iterator<Item = Point<usize>> + '_ {
+        let (start, end) = self.position;
+
+        (start.x..=end.x).map(move |x| Point::new(x, start.y))
+    }
+
+    /// Iterate over the cells surrounding the part, diagonals included. The part's own cells are
+    /// also returned, which is harmless since they can't hold a symbol.
+    fn surrounding_cells(&self) -> impl Iterator<Item = Point<usize>> + '_ {
+        let (start, end) = self.position;
+
+        (start.y.saturating_sub(1)..=end.y + 1).flat_map(move |y| {
+            (start.x.saturating_sub(1)..=end.x + 1).map(move |x| Point::new(x, y))
+        })
+    }
+}
+
+/// A cell of the schematic that is neither a digit nor a `.`.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Symbol {
+    pub value: char,
+    pub position: Point<usize>,
+}
+
+impl Symbol {
+    /// Iterate over the cells surrounding the symbol, diagonals included.
+    fn surrounding_cells(&self) -> impl Iterator<Item = Point<usize>> + '_ {
+        let Point { x, y } = self.position;
+
+        (y.saturating_sub(1)..=y + 1)
+            .flat_map(move |y| (x.saturating_sub(1)..=x + 1).map(move |x| Point::new(x, y)))
+    }
+}
+
+/// A `*` symbol adjacent to exactly two parts.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Gear {
+    pub position: Point<usize>,
+    pub parts: [EnginePart; 2],
+}
+
+impl Gear {
+    /// Get the gear ratio, the product of the values of its two parts.
+    pub fn ratio(&self) -> u32 {
+        self.parts[0].value * self.parts[1].value
+    }
+}
+
+/// The engine schematic, with its parts and symbols in reading order.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Board {
+    pub parts: Vec<EnginePart>,
+    pub symbols: Vec<Symbol>,
+}
+
+impl Board {
+    /// Get the parts adjacent to a symbol, diagonals included.
+    pub fn get_valid_parts(&self) -> Vec<EnginePart> {
+        let symbol_cells: HashSet<Point<usize>> = self.symbols.iter().map(|s| s.position).collect();
+
+        self.parts
+            .iter()
+            .filter(|p| p.surrounding_cells().any(|c| symbol_cells.contains(&c)))
+            .cloned()
+            .collect()
+    }
+
+    pub fn get_sum_of_valid_parts(&self) -> u32 {
+        self.get_valid_parts().iter().map(|p| p.value).sum()
+    }
+
+    /// Get the gears, with the two parts adjacent to each of them.
+    pub fn gears(&self) -> Vec<Gear> {
+        let part_cells: HashMap<Point<usize>, usize> = self
+            .parts
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, p)| p.cells().map(move |c| (c, idx)))
+            .collect();
+
+        self.symbols
+            .iter()
+            .filter(|s| s.value == '*')
+            .filter_map(|s| {
+                let adjacent = s
+                    .surrounding_cells()
+                    .filter_map(|c| part_cells.get(&c))
+                    .unique()
+                    .collect_vec();
+
+                match adjacent[..] {
+                    [&a, &b] => Some(Gear {
+                        position: s.position,
+                        parts: [self.parts[a].clone(), self.parts[b].clone()],
+                    }),
+                    _ => None,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_gear_ratios(&self) -> Vec<u32> {
+        self.gears().iter().map(Gear::ratio).collect()
+    }
+
+    pub fn get_sum_of_gear_ratios(&self) -> u32 {
+        self.get_gear_ratios().iter().sum()
+    }
+}
+
+/// Parse the engine schematic, e.g. to explore the parts and gears of an input from a notebook.
+pub fn parse(input: &[String]) -> Result<Board, ParseError> {
+    let mut parts = Vec::new();
+    let mut symbols = Vec::new();
+
+    let part_re = Regex::new(r"([0-9]+)").expect("Invalid regex");
+    let symbol_re = Regex::new(r"([^0-9.])").expect("Invalid regex");
+
+    let width = input.first().map_or(0, |l| l.len());
+
+    for (y, line) in input.iter().enumerate() {
+        if line.len() != width {
+            return Err(ParseError::new(
+                "day03",
+                y + 1,
+                line,
+                format!("expected {} columns, got {}", width, line.len()),
+            ));
+        }
+
+        for caps in part_re.captures_iter(line) {
+            let m = caps.get(1).unwrap();
+            let value = m.as_str().parse::<u32>().map_err(|_| {
+                ParseError::new(
+                    "day03",
+                    y + 1,
+                    line,
+                    format!("part number too large at column {}", m.start() + 1),
+                )
+            })?;
+            let start = Point::new(m.start(), y);
+            let end = Point::new(m.end() - 1, y);
+            parts.push(EnginePart {
+                value,
+                position: (start, end),
+            })
+        }
+        for caps in symbol_re.captures_iter(line) {
+            let m = caps.get(1).unwrap();
+            let value = m.as_str().chars().next().unwrap();
+            let position = Point::new(m.start(), y);
+            symbols.push(Symbol { value, position })
+        }
+    }
+
+    Ok(Board { parts, symbols })
+}
+
+/// Draw the schematic with the valid parts in green, the other parts in red and the gears in
+/// yellow.
+fn render_schematic(input: &[String], board: &Board) -> Frame {
+    let mut frame = Frame::from_lines(input);
+
+    let valid = board.get_valid_parts();
+    for part in &board.parts {
+        let color = if valid.contains(part) {
+            Color::Green
+        } else {
+            Color::Red
+        };
+
+        for c in part.cells() {
+            frame.paint(c.x, c.y, color);
+        }
+    }
+
+    for gear in board.gears() {
+        frame.paint(gear.position.x, gear.position.y, Color::Yellow);
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::strategies::{grid_lines, points_within};
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 3);
+
+    aoc_common::puzzle_fixture!("day03.txt");
+
+    #[rstest]
+    fn test_parse_board(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+
+        let expected = Board {
+            parts: vec![
+                EnginePart {
+                    value: 467,
+                    position: (Point { x: 0, y: 0 }, Point { x: 2, y: 0 }),
+                },
+                EnginePart {
+                    value: 114,
+                    position: (Point { x: 5, y: 0 }, Point { x: 7, y: 0 }),
+                },
+                EnginePart {
+                    value: 35,
+                    position: (Point { x: 2, y: 2 }, Point { x: 3, y: 2 }),
+                },
+                EnginePart {
+                    value: 633,
+                    position: (Point { x: 6, y: 2 }, Point { x: 8, y: 2 }),
+                },
+                EnginePart {
+                    value: 617,
+                    position: (Point { x: 0, y: 4 }, Point { x: 2, y: 4 }),
+                },
+                EnginePart {
+                    value: 58,
+                    position: (Point { x: 7, y: 5 }, Point { x: 8, y: 5 }),
+                },
+                EnginePart {
+                    value: 592,
+                    position: (Point { x: 2, y: 6 }, Point { x: 4, y: 6 }),
+                },
+                EnginePart {
+                    value: 755,
+                    position: (Point { x: 6, y: 7 }, Point { x: 8, y: 7 }),
+                },
+                EnginePart {
+                    value: 664,
+                    position: (Point { x: 1, y: 9 }, Point { x: 3, y: 9 }),
+                },
+                EnginePart {
+                    value: 598,
+                    position: (Point { x: 5, y: 9 }, Point { x: 7, y: 9 }),
+                },
+            ],
+            symbols: vec![
+                Symbol {
+                    value: '*',
+                    position: Point::new(3, 1),
+                },
+                Symbol {
+                    value: '#',
+                    position: Point::new(6, 3),
+                },
+                Symbol {
+                    value: '*',
+                    position: Point::new(3, 4),
+                },
+                Symbol {
+                    value: '+',
+                    position: Point::new(5, 5),
+                },
+                Symbol {
+                    value: '$',
+                    position: Point::new(3, 8),
+                },
+                Symbol {
+                    value: '*',
+                    position: Point::new(5, 8),
+                },
+            ],
+        };
+
+        assert_eq!(board, expected);
+    }
+
+    #[rstest]
+    #[case(vec!["467..114..", "...*......", "..35..633"], 3, "expected 10 columns, got 9")]
+    #[case(vec!["467..", "...*.", "99999999999"], 3, "expected 5 columns, got 11")]
+    #[case(vec!["99999999999", "...*......."], 1, "part number too large at column 1")]
+    fn test_parse_board_invalid(
+        #[case] input: Vec<&str>,
+        #[case] line_no: usize,
+        #[case] expected: &str,
+    ) {
+        let input: Vec<String> = input.into_iter().map(String::from).collect();
+
+        assert_eq!(
+            parse(&input),
+            Err(ParseError::new(
+                "day03",
+                line_no,
+                &input[line_no - 1],
+                expected
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_get_valid_parts(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+        let valid = board.get_valid_parts();
+
+        let expected = vec![
+            EnginePart {
+                value: 467,
+                position: (Point { x: 0, y: 0 }, Point { x: 2, y: 0 }),
+            },
+            EnginePart {
+                value: 35,
+                position: (Point { x: 2, y: 2 }, Point { x: 3, y: 2 }),
+            },
+            EnginePart {
+                value: 633,
+                position: (Point { x: 6, y: 2 }, Point { x: 8, y: 2 }),
+            },
+            EnginePart {
+                value: 617,
+                position: (Point { x: 0, y: 4 }, Point { x: 2, y: 4 }),
+            },
+            EnginePart {
+                value: 592,
+                position: (Point { x: 2, y: 6 }, Point { x: 4, y: 6 }),
+            },
+            EnginePart {
+                value: 755,
+                position: (Point { x: 6, y: 7 }, Point { x: 8, y: 7 }),
+            },
+            EnginePart {
+                value: 664,
+                position: (Point { x: 1, y: 9 }, Point { x: 3, y: 9 }),
+            },
+            EnginePart {
+                value: 598,
+                position: (Point { x: 5, y: 9 }, Point { x: 7, y: 9 }),
+            },
+        ];
+
+        assert_eq!(valid, expected);
+    }
+
+    #[rstest]
+    fn test_get_gear_ratios(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+        assert_eq!(board.get_gear_ratios(), vec![16345, 451490]);
+    }
+
+    #[rstest]
+    fn test_gears(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+
+        let gears = board
+            .gears()
+            .iter()
+            .map(|g| (g.position, g.parts[0].value, g.parts[1].value))
+            .collect_vec();
+
+        assert_eq!(
+            gears,
+            vec![(Point::new(3, 1), 467, 35), (Point::new(5, 8), 755, 598)]
+        );
+    }
+
+    #[rstest]
+    fn test_render_schematic(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+        let frame = render_schematic(&test_input, &board);
+
+        assert_eq!(frame.render_plain(), test_input.iter().join("\n") + "\n");
+
+        let color = |x, y| frame.get(x, y).color;
+        assert_eq!(color(0, 0), Some(Color::Green));
+        assert_eq!(color(7, 0), Some(Color::Red));
+        assert_eq!(color(8, 5), Some(Color::Red));
+        assert_eq!(color(3, 1), Some(Color::Yellow));
+        assert_eq!(color(5, 8), Some(Color::Yellow));
+        assert_eq!(color(3, 4), None);
+        assert_eq!(color(1, 1), None);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+        let res = board.get_sum_of_valid_parts();
+
+        assert_eq!(res, 4361);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let board = parse(&test_input).unwrap();
+        let res = board.get_sum_of_gear_ratios();
+
+        assert_eq!(res, 467835);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let board = parse(&input).unwrap();
+        board.get_sum_of_valid_parts()
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let board = parse(&input).unwrap();
+        board.get_sum_of_gear_ratios()
+    }
+
+    aoc_common::answer_tests!("day03", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day03", part1, part2);
+
+    aoc_common::perf_test!("day03", 10, puzzle_input(), solve);
+
+    const BOARD_ALPHABET: &[char] = &['1', '2', '7', '.', '.', '.', '.', '*', '#'];
+
+    /// The cells within one step of `(x, y)`, diagonals and the cell itself included.
+    fn neighbours(x: usize, y: usize) -> Vec<Point<usize>> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter_map(|(dx, dy)| {
+                Some(Point::new(
+                    x.checked_add_signed(dx)?,
+                    y.checked_add_signed(dy)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// The numbers of a board, with their value and the cells they cover.
+    fn naive_numbers(board: &[String]) -> Vec<(u32, Vec<Point<usize>>)> {
+        let mut numbers = Vec::new();
+
+        for (y, line) in board.iter().enumerate() {
+            let chars: Vec<char> = line.chars().collect();
+            let mut x = 0;
+
+            while x < chars.len() {
+                if !chars[x].is_ascii_digit() {
+                    x += 1;
+                    continue;
+                }
+
+                let start = x;
+                while x < chars.len() && chars[x].is_ascii_digit() {
+                    x += 1;
+                }
+
+                let value = line[start..x].parse().unwrap();
+                numbers.push((value, (start..x).map(|x| Point::new(x, y)).collect()));
+            }
+        }
+
+        numbers
+    }
+
+    fn cell(board: &[String], p: Point<usize>) -> Option<char> {
+        board.get(p.y)?.chars().nth(p.x)
+    }
+
+    fn naive_sum_of_valid_parts(board: &[String]) -> u32 {
+        naive_numbers(board)
+            .iter()
+            .filter(|(_, cells)| {
+                cells.iter().any(|c| {
+                    neighbours(c.x, c.y)
+                        .into_iter()
+                        .filter_map(|n| cell(board, n))
+                        .any(|v| v != '.' && !v.is_ascii_digit())
+                })
+            })
+            .map(|(value, _)| value)
+            .sum()
+    }
+
+    fn naive_sum_of_gear_ratios(board: &[String]) -> u32 {
+        let numbers = naive_numbers(board);
+
+        board
+            .iter()
+            .enumerate()
+            .flat_map(|(y, line)| line.match_indices('*').map(move |(x, _)| (x, y)))
+            .filter_map(|(x, y)| {
+                let around = neighbours(x, y);
+                let adjacent: Vec<u32> = numbers
+                    .iter()
+                    .filter(|(_, cells)| cells.iter().any(|c| around.contains(c)))
+                    .map(|(value, _)| *value)
+                    .collect();
+
+                (adjacent.len() == 2).then(|| adjacent[0] * adjacent[1])
+            })
+            .sum()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_symbol_surrounding_cells_are_neighbours(position in points_within(140, 140)) {
+            let symbol = Symbol { value: '*', position };
+
+            let mut cells: Vec<Point<usize>> = symbol.surrounding_cells().collect();
+            let mut expected = neighbours(position.x, position.y);
+            cells.sort_by_key(|p| (p.y, p.x));
+            expected.sort_by_key(|p| (p.y, p.x));
+
+            prop_assert_eq!(cells, expected);
+        }
+
+        #[test]
+        fn prop_sum_of_valid_parts_matches_naive(board in grid_lines(9, 9, BOARD_ALPHABET)) {
+            let parsed = parse(&board).unwrap();
+
+            prop_assert_eq!(parsed.get_sum_of_valid_parts(), naive_sum_of_valid_parts(&board));
+        }
+
+        #[test]
+        fn prop_sum_of_gear_ratios_matches_naive(board in grid_lines(9, 9, BOARD_ALPHABET)) {
+            let parsed = parse(&board).unwrap();
+
+            prop_assert_eq!(parsed.get_sum_of_gear_ratios(), naive_sum_of_gear_ratios(&board));
+        }
+    }
+}
@@ -0,0 +1,324 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, parse_lines, trace_flag, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day04` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day04.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if trace_flag() {
+        let cards = parse_cards(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        println!();
+        println!("Copies per card:");
+        for (card, copies) in cards.iter().zip(copies_per_card(&cards)) {
+            println!("  Card {}: {}", card.id, copies);
+        }
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let cards = parse_cards(input)?;
+
+    let p1 = get_sum_of_card_values(&cards);
+    let p2 = get_number_of_scratch_cards(&cards);
+
+    Ok((p1, p2))
+}
+
+fn get_sum_of_card_values(cards: &[Card]) -> u32 {
+    cards.iter().map(|c| c.value()).sum()
+}
+
+/// Get the number of copies of each card ending up in the pile, the original included. The cards
+/// are expected in order, as returned by [`parse_cards`]. The wins of a card past the last one are
+/// ignored.
+pub fn copies_per_card(cards: &[Card]) -> Vec<u32> {
+    let mut copies: Vec<u32> = vec![1; cards.len()];
+
+    for (idx, c) in cards.iter().enumerate() {
+        let wins = c.match_count() as usize;
+        let copies_of_current = copies[idx];
+        let last = (idx + wins).min(cards.len() - 1);
+
+        for copy in &mut copies[idx + 1..=last] {
+            *copy += copies_of_current;
+        }
+    }
+
+    copies
+}
+
+fn get_number_of_scratch_cards(cards: &[Card]) -> u32 {
+    copies_per_card(cards).iter().sum()
+}
+
+/// A scratch card. Since all numbers are below 128, the winning numbers and the numbers we have
+/// are stored as bitmasks, bit `n` being set if the number `n` is present.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Card {
+    id: u32,
+    winning_numbers: u128,
+    numbers: u128,
+}
+
+impl Card {
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get the number of winning numbers we have.
+    pub fn match_count(&self) -> u32 {
+        (self.numbers & self.winning_numbers).count_ones()
+    }
+
+    /// Get the points the card is worth in part 1.
+    pub fn value(&self) -> u32 {
+        let match_count = self.match_count();
+
+        if match_count == 0 {
+            return 0;
+        }
+
+        2u32.pow(match_count - 1)
+    }
+}
+
+fn parse_numbers(raw_numbers: &str) -> Result<u128, String> {
+    raw_numbers
+        .split(' ')
+        .filter(|n| !n.is_empty())
+        .try_fold(0, |mask, n| {
+            let n = n
+                .parse::<u32>()
+                .map_err(|_| format!("invalid number {:?}", n))?;
+
+            if n >= 128 {
+                return Err(format!("number too big: {}", n));
+            }
+
+            Ok(mask | 1 << n)
+        })
+}
+
+fn parse_card(entry: &str) -> Result<Card, String> {
+    let (title, data) = entry
+        .strip_prefix("Card ")
+        .and_then(|e| e.split_once(':'))
+        .ok_or("expected 'Card N:' prefix")?;
+
+    let id = title
+        .trim()
+        .parse::<u32>()
+        .map_err(|_| format!("invalid card id {:?}", title.trim()))?;
+
+    let (raw_winning_numbers, raw_numbers) = data
+        .split_once('|')
+        .ok_or("expected '|' between the winning numbers and the numbers")?;
+
+    Ok(Card {
+        id,
+        winning_numbers: parse_numbers(raw_winning_numbers)?,
+        numbers: parse_numbers(raw_numbers)?,
+    })
+}
+
+/// Parse the cards, which must be numbered in order from 1.
+pub fn parse_cards(input: &[String]) -> Result<Vec<Card>, ParseError> {
+    let cards = parse_lines("day04", input, parse_card)?;
+
+    if let Some((idx, card)) = cards
+        .iter()
+        .enumerate()
+        .find(|(idx, c)| c.id as usize != idx + 1)
+    {
+        let reason = format!("expected card {}, got card {}", idx + 1, card.id);
+        return Err(ParseError::new("day04", idx + 1, &input[idx], reason));
+    }
+
+    Ok(cards)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn mask(numbers: &[u32]) -> u128 {
+        numbers.iter().fold(0, |mask, n| mask | 1 << n)
+    }
+
+    aoc_common::example_fixture!(day = 4);
+
+    aoc_common::puzzle_fixture!("day04.txt");
+
+    #[rstest]
+    fn test_parse_cards(test_input: Vec<String>) {
+        let expected = vec![
+            Card {
+                id: 1,
+                winning_numbers: mask(&[41, 48, 83, 86, 17]),
+                numbers: mask(&[83, 86, 6, 31, 17, 9, 48, 53]),
+            },
+            Card {
+                id: 2,
+                winning_numbers: mask(&[13, 32, 20, 16, 61]),
+                numbers: mask(&[61, 30, 68, 82, 17, 32, 24, 19]),
+            },
+            Card {
+                id: 3,
+                winning_numbers: mask(&[1, 21, 53, 59, 44]),
+                numbers: mask(&[69, 82, 63, 72, 16, 21, 14, 1]),
+            },
+            Card {
+                id: 4,
+                winning_numbers: mask(&[41, 92, 73, 84, 69]),
+                numbers: mask(&[59, 84, 76, 51, 58, 5, 54, 83]),
+            },
+            Card {
+                id: 5,
+                winning_numbers: mask(&[87, 83, 26, 28, 32]),
+                numbers: mask(&[88, 30, 70, 12, 93, 22, 82, 36]),
+            },
+            Card {
+                id: 6,
+                winning_numbers: mask(&[31, 18, 13, 56, 72]),
+                numbers: mask(&[74, 77, 10, 23, 35, 67, 36, 11]),
+            },
+        ];
+
+        assert_eq!(parse_cards(&test_input).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("Game 1: 3 blue, 4 red", "expected 'Card N:' prefix")]
+    #[case("Card x: 1 2 | 3 4", "invalid card id \"x\"")]
+    #[case(
+        "Card 3: 1 2 3 4",
+        "expected '|' between the winning numbers and the numbers"
+    )]
+    #[case("Card 3: 1 2 | 3 a", "invalid number \"a\"")]
+    #[case("Card 3: 1 200 | 3 4", "number too big: 200")]
+    fn test_parse_cards_invalid_entry(#[case] entry: &str, #[case] expected: &str) {
+        let input = vec![
+            String::from("Card 1: 1 | 2"),
+            String::from("Card 2: 1 | 2"),
+            String::from(entry),
+        ];
+
+        assert_eq!(
+            parse_cards(&input),
+            Err(ParseError::new("day04", 3, entry, expected))
+        );
+    }
+
+    #[rstest]
+    #[case(vec!["Card 0: 1 | 1"], 1, "expected card 1, got card 0")]
+    #[case(vec!["Card 1: 1 | 1", "Card 3: 1 | 1"], 2, "expected card 2, got card 3")]
+    fn test_parse_cards_out_of_order(
+        #[case] input: Vec<&str>,
+        #[case] line_no: usize,
+        #[case] expected: &str,
+    ) {
+        let input: Vec<String> = input.into_iter().map(String::from).collect();
+
+        assert_eq!(
+            parse_cards(&input),
+            Err(ParseError::new(
+                "day04",
+                line_no,
+                &input[line_no - 1],
+                expected
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_get_card_match_count(test_input: Vec<String>) {
+        let values: Vec<u32> = parse_cards(&test_input)
+            .unwrap()
+            .iter()
+            .map(|c| c.match_count())
+            .collect();
+
+        assert_eq!(values, [4, 2, 2, 1, 0, 0]);
+    }
+
+    #[rstest]
+    fn test_get_card_value(test_input: Vec<String>) {
+        let values: Vec<u32> = parse_cards(&test_input)
+            .unwrap()
+            .iter()
+            .map(|c| c.value())
+            .collect();
+
+        assert_eq!(values, [8, 2, 2, 1, 0, 0]);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let cards = parse_cards(&test_input).unwrap();
+
+        let res = get_sum_of_card_values(&cards);
+
+        assert_eq!(res, 13);
+    }
+
+    #[rstest]
+    fn test_copies_per_card(test_input: Vec<String>) {
+        let cards = parse_cards(&test_input).unwrap();
+
+        assert_eq!(copies_per_card(&cards), vec![1, 2, 4, 8, 14, 1]);
+    }
+
+    #[rstest]
+    fn test_copies_per_card_wins_past_the_last_card() {
+        let input = vec![
+            String::from("Card 1: 1 2 3 | 1 2 3"),
+            String::from("Card 2: 1 2 | 1 2"),
+        ];
+        let cards = parse_cards(&input).unwrap();
+
+        assert_eq!(copies_per_card(&cards), vec![1, 2]);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let cards = parse_cards(&test_input).unwrap();
+
+        let res = get_number_of_scratch_cards(&cards);
+
+        assert_eq!(res, 30);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let cards = parse_cards(&input).unwrap();
+
+        get_sum_of_card_values(&cards)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let cards = parse_cards(&input).unwrap();
+
+        get_number_of_scratch_cards(&cards)
+    }
+
+    aoc_common::answer_tests!("day04", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day04", part1, part2);
+
+    aoc_common::perf_test!("day04", 2, puzzle_input(), solve);
+}
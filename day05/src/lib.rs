@@ -0,0 +1,1368 @@
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+use std::time::Instant;
+
+use aoc_common::dump::dump_parsed_flag;
+use aoc_common::progress::progress_bar;
+use aoc_common::viz::Color;
+use aoc_common::{format_duration, trace_flag, InputSource, ParseError};
+use serde::Serialize;
+
+/// Where the mapping chain is written with `--export dot`.
+const DOT_PATH: &str = "day05.dot";
+
+/// The colors of the seeds' paths in the DOT export, reused if there are more seeds.
+const SEED_COLORS: [Color; 6] = [
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Green,
+    Color::Rgb(255, 128, 0),
+    Color::Rgb(128, 0, 255),
+];
+
+/// Solve the puzzle input and print the answers, as the `day05` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day05.txt").read();
+
+    if let Some(format) = dump_parsed_flag() {
+        let plan = parse_plan(&input).unwrap_or_else(|e| panic!("{}", e));
+        print!("{}", format.dump(&plan));
+        return;
+    }
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.windows(2).any(|a| a[0] == "--export" && a[1] == "dot") {
+        let plan = parse_plan(&input).unwrap_or_else(|e| panic!("{}", e));
+        std::fs::write(DOT_PATH, render_dot(&plan))
+            .unwrap_or_else(|e| panic!("Unable to write {}: {}", DOT_PATH, e));
+        println!("Mapping chain written to {}", DOT_PATH);
+    }
+
+    if args.iter().any(|a| a == "--brute-force") {
+        let plan = parse_plan(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        let start = Instant::now();
+        let p2 = plan.get_lowest_seed_location_from_range_brute_force();
+        let t = start.elapsed().as_nanos();
+
+        println!();
+        println!("Part 2 (brute force): {}", p2);
+        println!("Duration: {}", format_duration(t));
+    }
+
+    if trace_flag() {
+        let plan = parse_plan(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        println!();
+        println!("Composed seed-to-location mappings:");
+        for m in plan
+            .get_seed_to_location_map()
+            .mappings
+            .iter()
+            .sorted_by_key(|m| m.src_start)
+        {
+            println!(
+                "  [{}, {}) -> [{}, {}) ({:+})",
+                m.src_start,
+                m.src_start + m.length,
+                m.dst_start,
+                m.dst_start + m.length,
+                m.dst_start - m.src_start
+            );
+        }
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let mut plan = parse_plan(input)?;
+
+    let p1 = plan.get_lowest_seed_location();
+    plan.add_implicit_mappings();
+    let p2 = plan.get_lowest_seed_location_from_range();
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, Default, Eq, PartialEq, Serialize)]
+struct PlantingPlan {
+    pub seeds: Vec<i64>,
+    pub maps: HashMap<Category, ConversionMap>,
+}
+
+impl PlantingPlan {
+    fn add_implicit_mappings(&mut self) {
+        for map in self.maps.values_mut() {
+            let mut range_starts: Vec<i64> = vec![0i64, (u32::MAX) as i64];
+            range_starts.extend(map.mappings.iter().map(|m| m.src_start));
+            range_starts.extend(map.mappings.iter().map(|m| m.src_start + m.length));
+
+            range_starts.sort();
+
+            let new_mappings = range_starts
+                .iter()
+                .tuple_windows()
+                .map(|(&start, end)| {
+                    if let Some(m) = map.mappings.iter().find(|m| m.src_start == start) {
+                        *m
+                    } else {
+                        Mapping {
+                            src_start: start,
+                            dst_start: start,
+                            length: end - start,
+                        }
+                    }
+                })
+                .collect();
+
+            map.mappings = new_mappings;
+        }
+    }
+}
+
+impl PlantingPlan {
+    /// Get the conversion maps from seeds to locations, in order, following the destination of
+    /// each map to the next one. Maps which aren't on the way are ignored.
+    fn get_chain(&self) -> Vec<&ConversionMap> {
+        let mut chain = vec![self.maps.get(SEED).unwrap()];
+
+        while chain.last().unwrap().dst.0 != LOCATION {
+            chain.push(self.maps.get(&chain.last().unwrap().dst).unwrap());
+        }
+
+        chain
+    }
+
+    fn get_location_for_seed(&self, seed: i64) -> i64 {
+        let mut map = self.maps.get(SEED).unwrap();
+        let mut location = map.get_dst_value(seed);
+
+        while map.dst.0 != LOCATION {
+            map = self.maps.get(&map.dst).unwrap();
+            location = map.get_dst_value(location);
+        }
+
+        location
+    }
+
+    /// Compose the conversion maps from seeds to locations into a single map.
+    fn get_seed_to_location_map(&self) -> ConversionMap {
+        let mut composed = self.maps.get(SEED).unwrap().clone();
+
+        while composed.dst.0 != LOCATION {
+            composed = composed.compose(self.maps.get(&composed.dst).unwrap());
+        }
+
+        composed
+    }
+
+    /// Check that the composed seed-to-location map agrees with the step-by-step lookup for the
+    /// given seeds.
+    fn composition_matches_steps(&self, seeds: &[i64]) -> bool {
+        let composed = self.get_seed_to_location_map();
+
+        seeds
+            .iter()
+            .all(|&s| composed.get_dst_value(s) == self.get_location_for_seed(s))
+    }
+
+    fn get_lowest_seed_location(&self) -> i64 {
+        debug_assert!(
+            self.composition_matches_steps(&self.seeds),
+            "Composed maps disagree with the step-by-step lookup"
+        );
+
+        self.seeds
+            .iter()
+            .map(|&s| self.get_location_for_seed(s))
+            .min()
+            .unwrap()
+    }
+
+    fn get_lowest_seed_location_from_range(&self) -> i64 {
+        let mut chain = self.get_chain().into_iter().rev();
+        let mut mappings: Vec<Mapping> = chain
+            .next()
+            .unwrap()
+            .mappings
+            .iter()
+            .sorted_by_key(|m| m.src_start)
+            .cloned()
+            .collect();
+
+        for conversion_map in chain {
+            mappings = conversion_map
+                .mappings
+                .iter()
+                .cartesian_product(&mappings)
+                .flat_map(|(m1, m2)| m1.intersection(m2))
+                .sorted_by_key(|m| m.src_start)
+                .dedup()
+                .collect();
+        }
+
+        let seed_ranges: Vec<Range> = self
+            .seeds
+            .chunks(2)
+            .map(|c| Range {
+                start: c[0],
+                end: c[0] + c[1],
+            })
+            .collect();
+
+        let candidates = mappings
+            .iter()
+            .map(|m| Range {
+                start: m.src_start,
+                end: m.src_start + m.length,
+            })
+            .cartesian_product(seed_ranges)
+            .filter_map(|(r1, r2)| r1.intersection(&r2).map(|r| r.start));
+
+        candidates
+            .sorted()
+            .dedup()
+            .map(|s| self.get_location_for_seed(s))
+            .min()
+            .unwrap()
+    }
+
+    /// Map every seed of every range to its location and keep the lowest. The ranges are split
+    /// in chunks which are processed in parallel.
+    fn get_lowest_seed_location_from_range_brute_force(&self) -> i64 {
+        const CHUNK_SIZE: i64 = 1 << 16;
+
+        let chunks: Vec<Range> = self
+            .seeds
+            .chunks(2)
+            .flat_map(|c| {
+                let (start, end) = (c[0], c[0] + c[1]);
+
+                (start..end)
+                    .step_by(CHUNK_SIZE as usize)
+                    .map(move |s| Range {
+                        start: s,
+                        end: (s + CHUNK_SIZE).min(end),
+                    })
+            })
+            .collect();
+
+        let progress = progress_bar(chunks.len() as u64, "seed ranges");
+
+        let lowest = chunks
+            .into_par_iter()
+            .map(|r| {
+                let lowest = (r.start..r.end)
+                    .map(|s| self.get_location_for_seed(s))
+                    .min()
+                    .unwrap();
+                progress.inc(1);
+
+                lowest
+            })
+            .min()
+            .unwrap();
+
+        progress.finish_and_clear();
+
+        lowest
+    }
+}
+
+/// Reverse lookups, mapping locations back to seeds. These provide an alternative strategy for
+/// part 2 which is used to cross-check the interval solver.
+#[cfg(test)]
+impl PlantingPlan {
+    /// Get the seed ending up at a location, if there is one.
+    fn get_seed_for_location(&self, location: i64) -> Option<i64> {
+        self.get_chain()
+            .iter()
+            .rev()
+            .try_fold(location, |value, map| map.get_src_value(value))
+    }
+
+    /// Find the lowest location by scanning locations upward and mapping each one back to its
+    /// seed, stopping at the first seed that belongs to one of the seed ranges.
+    fn get_lowest_seed_location_from_range_reverse(&self) -> i64 {
+        let seed_ranges: Vec<Range> = self
+            .seeds
+            .chunks(2)
+            .map(|c| Range {
+                start: c[0],
+                end: c[0] + c[1],
+            })
+            .collect();
+
+        (0..)
+            .find(|&l| {
+                self.get_seed_for_location(l)
+                    .is_some_and(|seed| seed_ranges.iter().any(|r| r.contains(seed)))
+            })
+            .unwrap()
+    }
+}
+
+/// The category at the start of the conversion chain.
+const SEED: &str = "seed";
+
+/// The category at the end of the conversion chain.
+const LOCATION: &str = "location";
+
+/// A category of the almanac, like `seed` or `soil`. Categories are only known by their names, so
+/// the conversion chain is built by following the maps from [`SEED`] to [`LOCATION`].
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Serialize)]
+#[serde(transparent)]
+struct Category(String);
+
+impl From<&str> for Category {
+    fn from(value: &str) -> Self {
+        Category(value.to_owned())
+    }
+}
+
+impl Borrow<str> for Category {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+struct ConversionMap {
+    src: Category,
+    dst: Category,
+    mappings: Vec<Mapping>,
+}
+
+impl ConversionMap {
+    fn get_dst_value(&self, src_value: i64) -> i64 {
+        self.mappings
+            .iter()
+            .filter_map(|m| m.get_dst_value(src_value))
+            .next()
+            .unwrap_or(src_value)
+    }
+
+    /// Get the source value mapped to a destination value, if there is one. A value outside of
+    /// every mapping's source range maps to itself, so each candidate is checked against
+    /// [`ConversionMap::get_dst_value`] rather than trusting the identity.
+    #[cfg(test)]
+    fn get_src_value(&self, dst_value: i64) -> Option<i64> {
+        self.mappings
+            .iter()
+            .filter_map(|m| m.get_src_value(dst_value))
+            .chain(std::iter::once(dst_value))
+            .find(|&src| self.get_dst_value(src) == dst_value)
+    }
+
+    /// Compose this map with the `next` one, whose source is this map's destination, into a map
+    /// from this map's source to `next`'s destination. Both maps are offsets over contiguous
+    /// ranges, so the composed map can only change offset where this map does, or where its
+    /// image crosses a boundary of `next`. Between two such breakpoints, a single mapping is
+    /// enough.
+    fn compose(&self, next: &ConversionMap) -> ConversionMap {
+        let bounds = |m: &Mapping| [m.src_start, m.src_start + m.length];
+
+        let mut breakpoints: Vec<i64> = self.mappings.iter().flat_map(bounds).collect();
+        for b in next.mappings.iter().flat_map(bounds) {
+            breakpoints.push(b);
+            breakpoints.extend(self.mappings.iter().filter_map(|m| m.get_src_value(b)));
+        }
+
+        let mappings = breakpoints
+            .into_iter()
+            .sorted()
+            .dedup()
+            .tuple_windows()
+            .map(|(start, end)| Mapping {
+                src_start: start,
+                dst_start: next.get_dst_value(self.get_dst_value(start)),
+                length: end - start,
+            })
+            .filter(|m| m.src_start != m.dst_start)
+            .collect();
+
+        ConversionMap {
+            src: self.src.clone(),
+            dst: next.dst.clone(),
+            mappings,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize)]
+struct Mapping {
+    dst_start: i64,
+    src_start: i64,
+    length: i64,
+}
+
+impl Mapping {
+    fn get_dst_value(&self, src_value: i64) -> Option<i64> {
+        if src_value < self.src_start {
+            return None;
+        }
+
+        let distance = src_value - self.src_start;
+
+        if distance < self.length {
+            Some(self.dst_start + distance)
+        } else {
+            None
+        }
+    }
+
+    fn get_src_value(&self, dst_value: i64) -> Option<i64> {
+        if dst_value < self.dst_start {
+            return None;
+        }
+
+        let distance = dst_value - self.dst_start;
+
+        if distance < self.length {
+            Some(self.src_start + distance)
+        } else {
+            None
+        }
+    }
+
+    fn intersection(&self, other: &Mapping) -> Vec<Mapping> {
+        let self_dst_range = Range {
+            start: self.dst_start,
+            end: self.dst_start + self.length,
+        };
+        let other_src_range = Range {
+            start: other.src_start,
+            end: other.src_start + other.length,
+        };
+
+        let range_ixn = self_dst_range.intersection(&other_src_range);
+        if range_ixn.is_none() {
+            return vec![];
+        }
+
+        let range_ixn = range_ixn.unwrap();
+        let offset = self.dst_start - self.src_start;
+
+        [
+            Mapping {
+                src_start: self.src_start,
+                dst_start: self.dst_start,
+                length: range_ixn.start - self.dst_start,
+            },
+            Mapping {
+                src_start: range_ixn.start - offset,
+                dst_start: range_ixn.start,
+                length: range_ixn.length(),
+            },
+            Mapping {
+                src_start: range_ixn.end - offset,
+                dst_start: range_ixn.end,
+                length: self.length - range_ixn.length() - (range_ixn.start - self.dst_start),
+            },
+        ]
+        .into_iter()
+        .filter(|&m| m.length > 0)
+        .collect()
+    }
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+struct Range {
+    start: i64,
+    end: i64,
+}
+
+impl Range {
+    fn length(&self) -> i64 {
+        self.end - self.start
+    }
+
+    #[cfg(test)]
+    fn contains(&self, value: i64) -> bool {
+        self.start <= value && value < self.end
+    }
+
+    fn intersection(&self, other: &Range) -> Option<Range> {
+        let start = self.start.max(other.start);
+        let end = self.end.min(other.end);
+
+        if start < end {
+            Some(Range { start, end })
+        } else {
+            None
+        }
+    }
+}
+
+fn parse_plan(input: &[String]) -> Result<PlantingPlan, ParseError> {
+    let first = input.first().map_or("", String::as_str);
+    let seeds = first
+        .strip_prefix("seeds: ")
+        .ok_or_else(|| ParseError::new("day05", 1, first, "expected 'seeds:' prefix"))?
+        .split(' ')
+        .map(|s| {
+            s.parse()
+                .map_err(|_| ParseError::new("day05", 1, first, format!("invalid seed {:?}", s)))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut maps = HashMap::new();
+    // The header of each map, by destination, to report where a broken chain goes astray.
+    let mut headers = HashMap::new();
+
+    let mut input_iter = input.iter().enumerate().skip(2);
+
+    while let Some((idx, header)) = input_iter.next() {
+        let (src, dst) =
+            parse_map_header(header).map_err(|e| ParseError::new("day05", idx + 1, header, e))?;
+
+        let mut mappings = Vec::new();
+
+        for (idx, e) in input_iter.by_ref() {
+            if e.is_empty() {
+                break;
+            }
+
+            mappings.push(parse_mapping(e).map_err(|r| ParseError::new("day05", idx + 1, e, r))?);
+        }
+
+        // mappings.sort_by_key(|m| m.dst_start);
+
+        headers.insert(dst.clone(), (idx, header));
+        maps.insert(src.clone(), ConversionMap { src, dst, mappings });
+    }
+
+    // Follow the maps from seeds to locations, so that a broken chain is reported here instead of
+    // failing while solving.
+    let mut category = SEED;
+    let mut visited = HashSet::new();
+
+    while category != LOCATION {
+        let error = |e: String| match headers.get(category) {
+            Some(&(idx, header)) => ParseError::new("day05", idx + 1, header, e),
+            None => ParseError::new("day05", 1, first, e),
+        };
+
+        if !visited.insert(category) {
+            return Err(error(format!("cycle through {}", category)));
+        }
+
+        let map = maps
+            .get(category)
+            .ok_or_else(|| error(format!("no map from {}", category)))?;
+        category = &map.dst.0;
+    }
+
+    Ok(PlantingPlan { seeds, maps })
+}
+
+fn parse_map_header(header: &str) -> Result<(Category, Category), String> {
+    let (src, dst) = header
+        .strip_suffix(" map:")
+        .and_then(|h| h.split_once("-to-"))
+        .ok_or("expected 'X-to-Y map:' header")?;
+
+    for category in [src, dst] {
+        if category.is_empty() || !category.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(format!("Invalid category: {:?}", category));
+        }
+    }
+
+    Ok((src.into(), dst.into()))
+}
+
+fn parse_mapping(entry: &str) -> Result<Mapping, String> {
+    let (dst_start, src_start, length) = entry
+        .split(' ')
+        .map(|i| i.parse().map_err(|_| format!("invalid number {:?}", i)))
+        .collect::<Result<Vec<i64>, _>>()?
+        .into_iter()
+        .collect_tuple()
+        .ok_or("expected 3 numbers")?;
+
+    Ok(Mapping {
+        dst_start,
+        src_start,
+        length,
+    })
+}
+
+/// Draw the chain of conversion maps as a Graphviz graph with a layer per category, from left to
+/// right. Each layer has a node per source range of the category's map, plus one for the values
+/// outside of them, and the path of each seed through the layers is drawn in its own color with
+/// the converted values as labels.
+fn render_dot(plan: &PlantingPlan) -> String {
+    let mut dot = String::from("digraph planting_plan {\n  rankdir=LR;\n  node [shape=box];\n");
+
+    dot.push_str("  subgraph cluster_seeds {\n    label=\"seeds\";\n");
+    for (idx, seed) in plan.seeds.iter().enumerate() {
+        dot.push_str(&format!("    seeds_{} [label=\"seed {}\"];\n", idx, seed));
+    }
+    dot.push_str("  }\n");
+
+    // The node of each seed in the current layer, and the value it has been converted to.
+    let mut current: Vec<(String, i64)> = plan
+        .seeds
+        .iter()
+        .enumerate()
+        .map(|(idx, &s)| (format!("seeds_{}", idx), s))
+        .collect();
+    let mut edges = Vec::new();
+
+    for m in plan.get_chain() {
+        let name = &m.src;
+
+        dot.push_str(&format!(
+            "  subgraph cluster_{} {{\n    label=\"{}\";\n",
+            name, name
+        ));
+        for (idx, mapping) in m
+            .mappings
+            .iter()
+            .enumerate()
+            .sorted_by_key(|(_, m)| m.src_start)
+        {
+            dot.push_str(&format!(
+                "    {}_{} [label=\"[{}, {})\\n{:+}\"];\n",
+                name,
+                idx,
+                mapping.src_start,
+                mapping.src_start + mapping.length,
+                mapping.dst_start - mapping.src_start
+            ));
+        }
+        dot.push_str(&format!(
+            "    {}_other [label=\"other values\\n+0\"];\n  }}\n",
+            name
+        ));
+
+        for (seed, (node, value)) in current.iter_mut().enumerate() {
+            let next = match m
+                .mappings
+                .iter()
+                .position(|r| r.get_dst_value(*value).is_some())
+            {
+                Some(idx) => format!("{}_{}", name, idx),
+                None => format!("{}_other", name),
+            };
+
+            edges.push((seed, node.clone(), next.clone(), *value));
+            *node = next;
+            *value = m.get_dst_value(*value);
+        }
+    }
+
+    dot.push_str("  subgraph cluster_location {\n    label=\"location\";\n");
+    for (seed, (node, value)) in current.iter().enumerate() {
+        dot.push_str(&format!(
+            "    location_{} [label=\"location {}\"];\n",
+            seed, value
+        ));
+        edges.push((seed, node.clone(), format!("location_{}", seed), *value));
+    }
+    dot.push_str("  }\n");
+
+    for (seed, from, to, value) in edges {
+        let color = SEED_COLORS[seed % SEED_COLORS.len()];
+
+        dot.push_str(&format!(
+            "  {} -> {} [label=\"{}\", color=\"{}\"];\n",
+            from,
+            to,
+            value,
+            color.hex()
+        ));
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 5);
+
+    aoc_common::puzzle_fixture!("day05.txt");
+
+    #[rstest]
+    fn test_parse_planting_maps(test_input: Vec<String>) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        let maps = HashMap::from([
+            (
+                Category::from("seed"),
+                ConversionMap {
+                    src: Category::from("seed"),
+                    dst: Category::from("soil"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 50,
+                            src_start: 98,
+                            length: 2,
+                        },
+                        Mapping {
+                            dst_start: 52,
+                            src_start: 50,
+                            length: 48,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("soil"),
+                ConversionMap {
+                    src: Category::from("soil"),
+                    dst: Category::from("fertilizer"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 0,
+                            src_start: 15,
+                            length: 37,
+                        },
+                        Mapping {
+                            dst_start: 37,
+                            src_start: 52,
+                            length: 2,
+                        },
+                        Mapping {
+                            dst_start: 39,
+                            src_start: 0,
+                            length: 15,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("fertilizer"),
+                ConversionMap {
+                    src: Category::from("fertilizer"),
+                    dst: Category::from("water"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 49,
+                            src_start: 53,
+                            length: 8,
+                        },
+                        Mapping {
+                            dst_start: 0,
+                            src_start: 11,
+                            length: 42,
+                        },
+                        Mapping {
+                            dst_start: 42,
+                            src_start: 0,
+                            length: 7,
+                        },
+                        Mapping {
+                            dst_start: 57,
+                            src_start: 7,
+                            length: 4,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("water"),
+                ConversionMap {
+                    src: Category::from("water"),
+                    dst: Category::from("light"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 88,
+                            src_start: 18,
+                            length: 7,
+                        },
+                        Mapping {
+                            dst_start: 18,
+                            src_start: 25,
+                            length: 70,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("light"),
+                ConversionMap {
+                    src: Category::from("light"),
+                    dst: Category::from("temperature"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 45,
+                            src_start: 77,
+                            length: 23,
+                        },
+                        Mapping {
+                            dst_start: 81,
+                            src_start: 45,
+                            length: 19,
+                        },
+                        Mapping {
+                            dst_start: 68,
+                            src_start: 64,
+                            length: 13,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("temperature"),
+                ConversionMap {
+                    src: Category::from("temperature"),
+                    dst: Category::from("humidity"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 0,
+                            src_start: 69,
+                            length: 1,
+                        },
+                        Mapping {
+                            dst_start: 1,
+                            src_start: 0,
+                            length: 69,
+                        },
+                    ],
+                },
+            ),
+            (
+                Category::from("humidity"),
+                ConversionMap {
+                    src: Category::from("humidity"),
+                    dst: Category::from("location"),
+                    mappings: vec![
+                        Mapping {
+                            dst_start: 60,
+                            src_start: 56,
+                            length: 37,
+                        },
+                        Mapping {
+                            dst_start: 56,
+                            src_start: 93,
+                            length: 4,
+                        },
+                    ],
+                },
+            ),
+        ]);
+
+        let expected = PlantingPlan {
+            seeds: vec![79, 14, 55, 13],
+            maps,
+        };
+
+        assert_eq!(plan, expected);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(50, 52)]
+    #[case(97, 99)]
+    #[case(98, 50)]
+    #[case(99, 51)]
+    #[case(100, 100)]
+    fn test_map_get_dst_value(test_input: Vec<String>, #[case] input: i64, #[case] expected: i64) {
+        let plan = parse_plan(&test_input).unwrap();
+        let map = plan.maps.get(SEED).unwrap();
+
+        assert_eq!(map.get_dst_value(input), expected);
+    }
+
+    #[rstest]
+    #[case(79, 82)]
+    #[case(14, 43)]
+    #[case(55, 86)]
+    #[case(13, 35)]
+    fn test_get_location_for_seed(
+        test_input: Vec<String>,
+        #[case] input: i64,
+        #[case] expected: i64,
+    ) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        assert_eq!(plan.get_location_for_seed(input), expected);
+    }
+
+    #[rstest]
+    #[case(0, Some(0))]
+    #[case(1, Some(1))]
+    #[case(52, Some(50))]
+    #[case(98, Some(96))]
+    #[case(99, Some(97))]
+    #[case(50, Some(98))]
+    #[case(51, Some(99))]
+    #[case(100, Some(100))]
+    fn test_map_get_src_value(
+        test_input: Vec<String>,
+        #[case] input: i64,
+        #[case] expected: Option<i64>,
+    ) {
+        let plan = parse_plan(&test_input).unwrap();
+        let map = plan.maps.get(SEED).unwrap();
+
+        assert_eq!(map.get_src_value(input), expected);
+    }
+
+    #[rstest]
+    #[case(82, 79)]
+    #[case(43, 14)]
+    #[case(86, 55)]
+    #[case(35, 13)]
+    fn test_get_seed_for_location(
+        test_input: Vec<String>,
+        #[case] input: i64,
+        #[case] expected: i64,
+    ) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        assert_eq!(plan.get_seed_for_location(input), Some(expected));
+    }
+
+    #[rstest]
+    fn test_get_seed_for_location_without_preimage() {
+        let input = aoc_common::parse_test_input(
+            "
+            seeds: 5 1
+
+            seed-to-location map:
+            100 5 1
+            ",
+        );
+
+        let plan = parse_plan(&input).unwrap();
+
+        assert_eq!(plan.get_seed_for_location(5), None);
+        assert_eq!(plan.get_seed_for_location(100), Some(5));
+        assert_eq!(plan.get_seed_for_location(6), Some(6));
+        assert_eq!(plan.get_lowest_seed_location_from_range_reverse(), 100);
+    }
+
+    #[rstest]
+    #[case(0, "seeds 79 14", "expected 'seeds:' prefix")]
+    #[case(0, "seeds: 79 1x", "invalid seed \"1x\"")]
+    #[case(2, "seed-to-soil:", "expected 'X-to-Y map:' header")]
+    #[case(2, "seed-to-so il map:", "Invalid category: \"so il\"")]
+    #[case(2, "seed-to-dirt map:", "no map from dirt")]
+    #[case(30, "humidity-to-seed map:", "cycle through seed")]
+    #[case(3, "50 98", "expected 3 numbers")]
+    #[case(4, "52 50 4B", "invalid number \"4B\"")]
+    fn test_parse_plan_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_plan(&input),
+            Err(ParseError::new("day05", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_plan_without_seed_map(test_input: Vec<String>) {
+        let mut input = test_input;
+        input[2] = String::from("dirt-to-soil map:");
+
+        assert_eq!(
+            parse_plan(&input),
+            Err(ParseError::new(
+                "day05",
+                1,
+                "seeds: 79 14 55 13",
+                "no map from seed"
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_renamed_and_additional_categories() {
+        let input = aoc_common::parse_test_input(
+            "
+            seeds: 1 5 10 1
+
+            seed-to-rock map:
+            100 0 8
+
+            rock-to-paper map:
+            0 0 1
+
+            paper-to-location map:
+            50 100 2
+
+            scissors-to-location map:
+            0 0 1000
+            ",
+        );
+
+        let mut plan = parse_plan(&input).unwrap();
+
+        assert_eq!(
+            plan.get_chain()
+                .iter()
+                .map(|m| m.src.0.as_str())
+                .collect_vec(),
+            vec!["seed", "rock", "paper"]
+        );
+        assert_eq!(plan.get_location_for_seed(1), 51);
+        assert_eq!(plan.get_location_for_seed(10), 10);
+        assert_eq!(plan.get_lowest_seed_location(), 10);
+        assert_eq!(plan.get_seed_for_location(51), Some(1));
+
+        plan.add_implicit_mappings();
+        assert_eq!(plan.get_lowest_seed_location_from_range(), 10);
+    }
+
+    #[rstest]
+    fn test_render_dot(test_input: Vec<String>) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        insta::assert_snapshot!(render_dot(&plan));
+    }
+
+    #[rstest]
+    fn test_render_dot_stops_at_location() {
+        let input = aoc_common::parse_test_input(
+            "
+            seeds: 1
+
+            seed-to-location map:
+            10 0 5
+
+            location-to-humidity map:
+            0 10 5
+            ",
+        );
+
+        let plan = parse_plan(&input).unwrap();
+        let dot = render_dot(&plan);
+
+        assert!(dot.contains("seeds_0 -> seed_0 [label=\"1\""));
+        assert!(dot.contains("seed_0 -> location_0 [label=\"11\""));
+        assert!(!dot.contains("humidity"));
+        assert_eq!(dot.matches("subgraph cluster_location").count(), 1);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        assert_eq!(plan.get_lowest_seed_location(), 35);
+    }
+
+    /// Check the composed seed-to-location map against the step-by-step lookup for each seed,
+    /// reporting the first seed where they differ.
+    fn assert_composition_matches(plan: &PlantingPlan, seeds: impl IntoIterator<Item = i64>) {
+        let composed = plan.get_seed_to_location_map();
+
+        for seed in seeds {
+            assert_eq!(
+                composed.get_dst_value(seed),
+                plan.get_location_for_seed(seed),
+                "Wrong location for seed {}",
+                seed
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_seed_to_location_map(test_input: Vec<String>, #[values(false, true)] implicit: bool) {
+        let mut plan = parse_plan(&test_input).unwrap();
+        if implicit {
+            plan.add_implicit_mappings();
+        }
+
+        assert_composition_matches(&plan, 0..=110);
+    }
+
+    #[rstest]
+    fn test_seed_to_location_map_full_input() {
+        aoc_common::require_input!("day05.txt");
+        let puzzle_input = puzzle_input();
+
+        let plan = parse_plan(&puzzle_input).unwrap();
+
+        // The lookups can only diverge around the bounds of the mappings, or of their images.
+        let bounds: Vec<i64> = plan
+            .maps
+            .values()
+            .flat_map(|map| &map.mappings)
+            .flat_map(|m| {
+                [
+                    m.src_start,
+                    m.src_start + m.length,
+                    m.dst_start,
+                    m.dst_start + m.length,
+                ]
+            })
+            .collect();
+
+        assert_composition_matches(
+            &plan,
+            plan.seeds
+                .iter()
+                .copied()
+                .chain(bounds.iter().flat_map(|&b| [b - 1, b, b + 1])),
+        );
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let mut plan = parse_plan(&test_input).unwrap();
+        plan.add_implicit_mappings();
+
+        assert_eq!(plan.get_lowest_seed_location_from_range(), 46);
+    }
+
+    #[rstest]
+    fn test_p2_reverse(test_input: Vec<String>) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        assert_eq!(plan.get_lowest_seed_location_from_range_reverse(), 46);
+    }
+
+    #[rstest]
+    #[ignore]
+    fn test_p2_reverse_full_input() {
+        aoc_common::require_input!("day05.txt");
+        let puzzle_input = puzzle_input();
+
+        let plan = parse_plan(&puzzle_input).unwrap();
+
+        assert_eq!(plan.get_lowest_seed_location_from_range_reverse(), 46294175);
+    }
+
+    #[rstest]
+    fn test_p2_brute_force(test_input: Vec<String>) {
+        let plan = parse_plan(&test_input).unwrap();
+
+        assert_eq!(plan.get_lowest_seed_location_from_range_brute_force(), 46);
+    }
+
+    #[rstest]
+    #[ignore]
+    fn test_p2_brute_force_full_input() {
+        aoc_common::require_input!("day05.txt");
+        let puzzle_input = puzzle_input();
+
+        let plan = parse_plan(&puzzle_input).unwrap();
+
+        assert_eq!(
+            plan.get_lowest_seed_location_from_range_brute_force(),
+            46294175
+        );
+    }
+
+    #[rstest]
+    fn test_p2_variants_agree(test_input: Vec<String>) {
+        let mut plan = parse_plan(&test_input).unwrap();
+        plan.add_implicit_mappings();
+
+        aoc_common::assert_variants_agree(&[
+            ("intervals", &|| plan.get_lowest_seed_location_from_range()),
+            ("reverse", &|| {
+                plan.get_lowest_seed_location_from_range_reverse()
+            }),
+            ("brute force", &|| {
+                plan.get_lowest_seed_location_from_range_brute_force()
+            }),
+        ]);
+    }
+
+    #[rstest]
+    #[ignore]
+    fn test_p2_variants_agree_full_input() {
+        aoc_common::require_input!("day05.txt");
+        let puzzle_input = puzzle_input();
+
+        let mut plan = parse_plan(&puzzle_input).unwrap();
+        plan.add_implicit_mappings();
+
+        aoc_common::assert_variants_agree(&[
+            ("intervals", &|| plan.get_lowest_seed_location_from_range()),
+            ("reverse", &|| {
+                plan.get_lowest_seed_location_from_range_reverse()
+            }),
+            ("brute force", &|| {
+                plan.get_lowest_seed_location_from_range_brute_force()
+            }),
+        ]);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let plan = parse_plan(&input).unwrap();
+        plan.get_lowest_seed_location()
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let mut plan = parse_plan(&input).unwrap();
+        plan.add_implicit_mappings();
+        plan.get_lowest_seed_location_from_range()
+    }
+
+    aoc_common::answer_tests!("day05", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day05", part1, part2);
+
+    aoc_common::perf_test!("day05", 20, puzzle_input(), solve);
+
+    fn range_strategy() -> impl Strategy<Value = Range> {
+        (0i64..1000, 1i64..200).prop_map(|(start, length)| Range {
+            start,
+            end: start + length,
+        })
+    }
+
+    fn mapping_strategy() -> impl Strategy<Value = Mapping> {
+        (0i64..1000, 0i64..1000, 1i64..200).prop_map(|(src_start, dst_start, length)| Mapping {
+            src_start,
+            dst_start,
+            length,
+        })
+    }
+
+    /// Pairs of mappings where the first one's destination overlaps the second one's source.
+    fn overlapping_mappings_strategy() -> impl Strategy<Value = (Mapping, Mapping)> {
+        (mapping_strategy(), 0i64..1000, 1i64..200, any::<u16>()).prop_map(
+            |(a, dst_start, length, seed)| {
+                // Slide b's source range anywhere it still shares at least one value with a's destination range.
+                let offset = i64::from(seed) % (a.length + length - 1) - (length - 1);
+                let b = Mapping {
+                    src_start: a.dst_start + offset,
+                    dst_start,
+                    length,
+                };
+
+                (a, b)
+            },
+        )
+    }
+
+    /// Conversion maps whose mappings don't overlap, like the ones of the puzzle.
+    fn conversion_map_strategy(
+        src: Category,
+        dst: Category,
+    ) -> impl Strategy<Value = ConversionMap> {
+        prop::collection::vec((0i64..50, 1i64..100, 0i64..1000), 0..6).prop_map(move |entries| {
+            let mut start = 0;
+            let mappings = entries
+                .into_iter()
+                .map(|(gap, length, dst_start)| {
+                    let src_start = start + gap;
+                    start = src_start + length;
+
+                    Mapping {
+                        src_start,
+                        dst_start,
+                        length,
+                    }
+                })
+                .collect();
+
+            ConversionMap {
+                src: src.clone(),
+                dst: dst.clone(),
+                mappings,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_range_intersection_is_commutative(a in range_strategy(), b in range_strategy()) {
+            prop_assert_eq!(a.intersection(&b), b.intersection(&a));
+        }
+
+        #[test]
+        fn prop_range_intersection_matches_pointwise_containment(
+            a in range_strategy(),
+            b in range_strategy(),
+            value in 0i64..1200,
+        ) {
+            let in_both = a.contains(value) && b.contains(value);
+            let in_ixn = a.intersection(&b).is_some_and(|r| r.contains(value));
+
+            prop_assert_eq!(in_ixn, in_both);
+        }
+
+        #[test]
+        fn prop_mapping_split_covers_the_original((a, b) in overlapping_mappings_strategy()) {
+            let pieces = a.intersection(&b);
+            prop_assert!(!pieces.is_empty());
+
+            let mut start = a.src_start;
+            for p in pieces.iter().sorted_by_key(|p| p.src_start) {
+                prop_assert_eq!(p.src_start, start);
+                prop_assert_eq!(p.dst_start - p.src_start, a.dst_start - a.src_start);
+                start += p.length;
+            }
+
+            prop_assert_eq!(start, a.src_start + a.length);
+        }
+
+        #[test]
+        fn prop_mapping_split_matches_pointwise_mapping(
+            (a, b) in overlapping_mappings_strategy(),
+            value in 0i64..1200,
+        ) {
+            let pieces = a.intersection(&b);
+
+            let mapped: Vec<i64> = pieces.iter().filter_map(|p| p.get_dst_value(value)).collect();
+
+            match a.get_dst_value(value) {
+                Some(dst) => {
+                    prop_assert_eq!(mapped, vec![dst]);
+
+                    // Each piece maps either entirely into or entirely out of b's source range.
+                    let piece = pieces.iter().find(|p| p.get_dst_value(value).is_some()).unwrap();
+                    let piece_in_b = b.get_dst_value(piece.dst_start).is_some();
+                    prop_assert_eq!(b.get_dst_value(dst).is_some(), piece_in_b);
+                }
+                None => prop_assert!(mapped.is_empty()),
+            }
+        }
+
+        #[test]
+        fn prop_composed_map_matches_pointwise_mapping(
+            first in conversion_map_strategy(Category::from("seed"), Category::from("soil")),
+            second in conversion_map_strategy(Category::from("soil"), Category::from("fertilizer")),
+            value in 0i64..1200,
+        ) {
+            let composed = first.compose(&second);
+
+            prop_assert_eq!(&composed.src, &Category::from("seed"));
+            prop_assert_eq!(&composed.dst, &Category::from("fertilizer"));
+            prop_assert_eq!(
+                composed.get_dst_value(value),
+                second.get_dst_value(first.get_dst_value(value))
+            );
+        }
+    }
+}
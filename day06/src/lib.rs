@@ -0,0 +1,308 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day06` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day06.txt").read();
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let mut kerning = KERNING;
+    for arg in &args {
+        match arg.as_str() {
+            "--part1-kerning" => kerning[0] = true,
+            "--part1-no-kerning" => kerning[0] = false,
+            "--part2-kerning" => kerning[1] = true,
+            "--part2-no-kerning" => kerning[1] = false,
+            _ => {}
+        }
+    }
+
+    let start = Instant::now();
+
+    let (r1, r2) =
+        solve_with_kerning(input.as_slice(), kerning).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+}
+
+/// Whether the spaces between the digits are ignored for each part, unless overridden with
+/// `--partN-kerning` or `--partN-no-kerning`.
+const KERNING: [bool; 2] = [false, true];
+
+/// Solve both parts, with the spaces between the digits ignored as in `KERNING`.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    solve_with_kerning(input, KERNING)
+}
+
+fn solve_with_kerning(
+    input: &[String],
+    kerning: [bool; 2],
+) -> Result<(impl Display, impl Display), ParseError> {
+    let races = parse(input, kerning[0])?;
+    let p1 = get_error_margin(&races);
+
+    let races = parse(input, kerning[1])?;
+    let p2 = get_error_margin(&races);
+
+    Ok((p1, p2))
+}
+
+/// A race: how long it lasts, and the record distance to beat.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Race {
+    pub time: u64,
+    pub record: u64,
+}
+
+impl Race {
+    /// Count the hold durations that beat the record. The distance `h * (time - h)` is symmetric
+    /// around `time / 2` and increases up to it, so the shortest winning hold is found with a
+    /// binary search over `0..=time / 2` and every hold up to its mirror also wins. Distances are
+    /// computed in `u128` to stay exact for any `u64` time.
+    pub fn get_number_of_winning_strategies(&self) -> u64 {
+        let wins = |h: u64| h as u128 * (self.time - h) as u128 > self.record as u128;
+
+        let half = self.time / 2;
+        if !wins(half) {
+            return 0;
+        }
+
+        let (mut lo, mut hi) = (0, half);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if wins(mid) {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+
+        self.time - (lo * 2) + 1
+    }
+}
+
+/// Parse the values of the line at `idx`, which must start with `prefix`.
+fn parse_line<T>(
+    input: &[String],
+    idx: usize,
+    prefix: &str,
+    parse_values: impl Fn(&str) -> Result<T, String>,
+) -> Result<T, ParseError> {
+    let line = input.get(idx).map_or("", String::as_str);
+
+    line.strip_prefix(prefix)
+        .ok_or_else(|| format!("expected '{}' prefix", prefix))
+        .and_then(parse_values)
+        .map_err(|e| ParseError::new("day06", idx + 1, line, e))
+}
+
+fn parse_numbers(values: &str) -> Result<Vec<u64>, String> {
+    values
+        .split_whitespace()
+        .map(|s| s.parse().map_err(|_| format!("invalid number {:?}", s)))
+        .collect()
+}
+
+/// Parse the values as a single number, ignoring the spaces between its digits.
+fn parse_kerned_number(values: &str) -> Result<u64, String> {
+    let digits: String = values.chars().filter(|c| !c.is_whitespace()).collect();
+
+    digits
+        .parse()
+        .map_err(|_| format!("invalid number {:?}", digits))
+}
+
+/// Parse the races. When ignoring the spaces, the values of each line are read as a single
+/// number, giving a single race, as in part 2. Otherwise, each column is a race, as in part 1.
+pub fn parse(input: &[String], ignore_spaces: bool) -> Result<Vec<Race>, ParseError> {
+    let parse_values = |values: &str| match ignore_spaces {
+        true => parse_kerned_number(values).map(|n| vec![n]),
+        false => parse_numbers(values),
+    };
+
+    let times = parse_line(input, 0, "Time:", parse_values)?;
+    let records = parse_line(input, 1, "Distance:", |values| {
+        let records = parse_values(values)?;
+
+        if records.len() != times.len() {
+            return Err(format!(
+                "expected {} records, got {}",
+                times.len(),
+                records.len()
+            ));
+        }
+
+        Ok(records)
+    })?;
+
+    Ok(times
+        .into_iter()
+        .zip(records)
+        .map(|(time, record)| Race { time, record })
+        .collect())
+}
+
+fn get_error_margin(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|r| r.get_number_of_winning_strategies())
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 6);
+
+    aoc_common::puzzle_fixture!("day06.txt");
+
+    #[rstest]
+    fn test_parse_races(test_input: Vec<String>) {
+        let races = parse(&test_input, false).unwrap();
+
+        let expected = vec![
+            Race { time: 7, record: 9 },
+            Race {
+                time: 15,
+                record: 40,
+            },
+            Race {
+                time: 30,
+                record: 200,
+            },
+        ];
+
+        assert_eq!(races, expected);
+    }
+
+    #[rstest]
+    fn test_parse_race(test_input: Vec<String>) {
+        let races = parse(&test_input, true).unwrap();
+
+        assert_eq!(
+            races,
+            vec![Race {
+                time: 71530,
+                record: 940200
+            }]
+        );
+    }
+
+    #[rstest]
+    #[case(0, "Tim: 7 15 30", "expected 'Time:' prefix")]
+    #[case(0, "Time: 7 1S 30", "invalid number \"1S\"")]
+    #[case(1, "Distance: 9 40", "expected 3 records, got 2")]
+    fn test_parse_races_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse(&input, false),
+            Err(ParseError::new("day06", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    #[case(false, vec![(7, 9), (15, 40), (30, 200), (4, 3)])]
+    #[case(true, vec![(715304, 9402003)])]
+    fn test_parse_any_number_of_races(
+        #[case] ignore_spaces: bool,
+        #[case] expected: Vec<(u64, u64)>,
+    ) {
+        let input = vec![
+            String::from("Time:      7  15   30  4"),
+            String::from("Distance:  9  40  200  3"),
+        ];
+
+        let races = parse(&input, ignore_spaces).unwrap();
+
+        assert_eq!(
+            races,
+            expected
+                .into_iter()
+                .map(|(time, record)| Race { time, record })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn test_parse_race_invalid() {
+        let input = vec![String::from("Time: 7 15 30")];
+
+        assert_eq!(
+            parse(&input, true),
+            Err(ParseError::new(
+                "day06",
+                2,
+                "",
+                "expected 'Distance:' prefix"
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(7, 9, 4)]
+    #[case(30, 200, 9)]
+    #[case(4, 4, 0)]
+    #[case(4, 3, 1)]
+    #[case(5, 6, 0)]
+    #[case(5, 5, 2)]
+    #[case(0, 0, 0)]
+    #[case(1 << 32, (1 << 62) - 1, 1)]
+    #[case(1 << 32, 1 << 62, 0)]
+    #[case(u64::MAX, 0, u64::MAX - 1)]
+    #[case(u64::MAX, u64::MAX, u64::MAX - 3)]
+    fn test_get_number_of_winning_strategies(
+        #[case] time: u64,
+        #[case] record: u64,
+        #[case] expected: u64,
+    ) {
+        let race = Race { time, record };
+
+        assert_eq!(race.get_number_of_winning_strategies(), expected);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let races = parse(&test_input, false).unwrap();
+
+        assert_eq!(get_error_margin(&races), 288);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let races = parse(&test_input, true).unwrap();
+
+        assert_eq!(get_error_margin(&races), 71503);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let races = parse(&input, false).unwrap();
+        get_error_margin(&races)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let races = parse(&input, true).unwrap();
+        get_error_margin(&races)
+    }
+
+    aoc_common::answer_tests!("day06", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day06", part1, part2);
+
+    aoc_common::perf_test!("day06", 1, puzzle_input(), solve);
+}
@@ -0,0 +1,612 @@
+use itertools::Itertools;
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::time::Instant;
+
+use aoc_common::{format_duration, parse_lines, trace_flag, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day07` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day07.txt").read();
+
+    let (p1_rules, p2_rules) = parse_rules_args(std::env::args().skip(1));
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve_with_rules(input.as_slice(), &p1_rules, &p2_rules)
+        .unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if trace_flag() || std::env::args().any(|a| a == "--table") {
+        for (part, rules) in [(1, &p1_rules), (2, &p2_rules)] {
+            let hands = parse_hands(&input, rules).unwrap_or_else(|e| panic!("{}", e));
+
+            println!();
+            println!("Part {}:", part);
+            print!("{}", render_ranked_hands(&hands, rules));
+        }
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    solve_with_rules(input, &Rules::standard(), &Rules::with_jokers())
+}
+
+/// Solve both parts of the puzzle, with the given rules for each part instead of the usual ones,
+/// e.g. to play with another wildcard or ranking.
+pub fn solve_with_rules(
+    input: &[String],
+    p1_rules: &Rules,
+    p2_rules: &Rules,
+) -> Result<(impl Display, impl Display), ParseError> {
+    let hands = parse_hands(input, p1_rules)?;
+    let p1 = get_total_winnings(&hands);
+    let hands = parse_hands(input, p2_rules)?;
+    let p2 = get_total_winnings(&hands);
+
+    Ok((p1, p2))
+}
+
+/// The type of a hand, from the weakest to the strongest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandStrength {
+    HighCard,
+    OnePair,
+    TwoPairs,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+impl HandStrength {
+    /// Classify a hand from the number of occurrences of each card value. Jokers (value 0) are
+    /// added to the most frequent card, which always yields the best possible hand. The other
+    /// cards are valued from 2 to 14, and a higher value panics.
+    pub fn from_cards(cards: &[u8; 5]) -> Self {
+        let mut counts = [0u8; 15];
+        for &c in cards {
+            counts[c as usize] += 1;
+        }
+
+        let jokers = counts[0];
+        let (mut first, mut second) = (0, 0);
+
+        for &n in &counts[1..] {
+            if n > first {
+                second = first;
+                first = n;
+            } else if n > second {
+                second = n;
+            }
+        }
+
+        match (first + jokers, second) {
+            (5, _) => HandStrength::FiveOfAKind,
+            (4, _) => HandStrength::FourOfAKind,
+            (3, 2) => HandStrength::FullHouse,
+            (3, _) => HandStrength::ThreeOfAKind,
+            (2, 2) => HandStrength::TwoPairs,
+            (2, _) => HandStrength::OnePair,
+            _ => HandStrength::HighCard,
+        }
+    }
+}
+
+impl Display for HandStrength {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HandStrength::HighCard => "High card",
+            HandStrength::OnePair => "One pair",
+            HandStrength::TwoPairs => "Two pairs",
+            HandStrength::ThreeOfAKind => "Three of a kind",
+            HandStrength::FullHouse => "Full house",
+            HandStrength::FourOfAKind => "Four of a kind",
+            HandStrength::FiveOfAKind => "Five of a kind",
+        };
+
+        f.pad(name)
+    }
+}
+
+/// A hand of cards, given by their values as in [`HandStrength::from_cards`], and its bid.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Hand {
+    cards: [u8; 5],
+    bid: u32,
+    strength: HandStrength,
+}
+
+impl Hand {
+    pub fn new(cards: [u8; 5], bid: u32) -> Self {
+        Hand {
+            cards,
+            bid,
+            strength: HandStrength::from_cards(&cards),
+        }
+    }
+
+    pub fn cards(&self) -> [u8; 5] {
+        self.cards
+    }
+
+    pub fn bid(&self) -> u32 {
+        self.bid
+    }
+
+    pub fn strength(&self) -> HandStrength {
+        self.strength
+    }
+
+    /// Order hands by strength, then card by card from the first one when they're of the same
+    /// strength. The bids are ignored.
+    pub fn compare(&self, other: &Hand) -> Ordering {
+        (self.strength, self.cards).cmp(&(other.strength, other.cards))
+    }
+}
+
+/// The cards of the standard rules, from the weakest to the strongest.
+const CARDS: &str = "23456789TJQKA";
+
+/// How cards are valued: their ranking, from the weakest to the strongest, and an optional
+/// wildcard. The wildcard is weaker than any other card on its own, but counts as whichever card
+/// makes the strongest hand.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rules {
+    ranking: Vec<char>,
+    wildcard: Option<char>,
+}
+
+impl Rules {
+    /// Create rules from a ranking of at most 13 distinct cards, from the weakest to the
+    /// strongest, and an optional wildcard, which must be one of the ranked cards.
+    pub fn new(ranking: &str, wildcard: Option<char>) -> Result<Self, String> {
+        let ranking = ranking.chars().collect_vec();
+
+        if ranking.len() > 13 {
+            return Err(format!("expected at most 13 cards, got {}", ranking.len()));
+        }
+        if let Some(c) = ranking.iter().duplicates().next() {
+            return Err(format!("duplicate card {:?}", c));
+        }
+        if let Some(c) = wildcard.filter(|c| !ranking.contains(c)) {
+            return Err(format!("wildcard {:?} isn't ranked", c));
+        }
+
+        Ok(Rules { ranking, wildcard })
+    }
+
+    /// The rules of part 1, without a wildcard.
+    pub fn standard() -> Self {
+        Rules::new(CARDS, None).unwrap()
+    }
+
+    /// The rules of part 2, where `J` is a joker.
+    pub fn with_jokers() -> Self {
+        Rules::new(CARDS, Some('J')).unwrap()
+    }
+
+    /// Get the value of a card, 0 for the wildcard, and from 2 for the weakest card of the ranking
+    /// otherwise.
+    fn get_card_value(&self, c: char) -> Result<u8, String> {
+        if self.wildcard == Some(c) {
+            return Ok(0);
+        }
+
+        self.ranking
+            .iter()
+            .position(|&r| r == c)
+            .map(|idx| idx as u8 + 2)
+            .ok_or_else(|| format!("invalid card {:?}", c))
+    }
+
+    /// Get the label of a card from its value.
+    fn get_card_label(&self, value: u8) -> char {
+        match value {
+            0 => self.wildcard.expect("No wildcard in the rules"),
+            _ => self.ranking[value as usize - 2],
+        }
+    }
+}
+
+/// Parse the hands and their bids, valuing the cards with the given rules.
+pub fn parse_hands(input: &[String], rules: &Rules) -> Result<Vec<Hand>, ParseError> {
+    parse_lines("day07", input, |entry| parse_hand(entry, rules))
+}
+
+fn parse_hand(entry: &str, rules: &Rules) -> Result<Hand, String> {
+    let (raw_cards, bid) = entry
+        .split_once(' ')
+        .ok_or("expected cards and a bid separated by a space")?;
+
+    let cards: Vec<u8> = raw_cards
+        .chars()
+        .map(|c| rules.get_card_value(c))
+        .collect::<Result<_, _>>()?;
+    let cards: [u8; 5] = cards
+        .try_into()
+        .map_err(|c: Vec<u8>| format!("expected 5 cards, got {}", c.len()))?;
+
+    let bid = bid.parse().map_err(|_| format!("invalid bid {:?}", bid))?;
+
+    Ok(Hand::new(cards, bid))
+}
+
+/// Get the rules of both parts from the command line. `--ranking=CARDS` replaces the ranking of the
+/// cards in both parts, from the weakest to the strongest, and `--wildcard=CARD` replaces the joker
+/// of part 2.
+fn parse_rules_args(args: impl IntoIterator<Item = String>) -> (Rules, Rules) {
+    let mut ranking = CARDS.to_string();
+    let mut wildcard = 'J';
+
+    for arg in args {
+        if let Some(value) = arg.strip_prefix("--ranking=") {
+            ranking = value.to_string();
+        } else if let Some(value) = arg.strip_prefix("--wildcard=") {
+            let mut chars = value.chars();
+            wildcard = match (chars.next(), chars.next()) {
+                (Some(c), None) => c,
+                _ => panic!("Invalid wildcard, expected a single card: {}", value),
+            };
+        }
+    }
+
+    let rules = |wildcard| {
+        Rules::new(&ranking, wildcard).unwrap_or_else(|e| panic!("Invalid rules: {}", e))
+    };
+
+    (rules(None), rules(Some(wildcard)))
+}
+
+/// Format cards back to their labels, wildcards included.
+fn format_cards(cards: &[u8; 5], rules: &Rules) -> String {
+    cards.iter().map(|&c| rules.get_card_label(c)).collect()
+}
+
+fn get_sorted_hands(hands: &[Hand]) -> Vec<&Hand> {
+    hands.iter().sorted_by(|a, b| a.compare(b)).collect_vec()
+}
+
+/// Draw the hands as a table sorted by rank, with their card values, strength, bid and
+/// contribution to the total winnings.
+fn render_ranked_hands(hands: &[Hand], rules: &Rules) -> String {
+    let mut table = format!(
+        "{:>4}  {:5}  {:14}  {:15}  {:>5}  {:>9}\n",
+        "Rank", "Cards", "Values", "Strength", "Bid", "Winnings"
+    );
+    let mut total = 0;
+
+    for (idx, hand) in get_sorted_hands(hands).iter().enumerate() {
+        let rank = idx + 1;
+        let winnings = hand.bid as usize * rank;
+        total += winnings;
+
+        table.push_str(&format!(
+            "{:>4}  {:5}  {:14}  {:15}  {:>5}  {:>9}\n",
+            rank,
+            format_cards(&hand.cards, rules),
+            hand.cards.iter().map(|c| format!("{:>2}", c)).join(" "),
+            hand.strength,
+            hand.bid,
+            winnings
+        ));
+    }
+
+    table.push_str(&format!("Total winnings: {}\n", total));
+
+    table
+}
+
+fn get_total_winnings(hands: &[Hand]) -> usize {
+    get_sorted_hands(hands)
+        .iter()
+        .enumerate()
+        .map(|(idx, h)| h.bid as usize * (idx + 1))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 7);
+
+    aoc_common::puzzle_fixture!("day07.txt");
+
+    #[rstest]
+    fn test_parse_hands(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::standard()).unwrap();
+
+        let expected_hands = vec![
+            Hand::new([3, 2, 10, 3, 13], 765),
+            Hand::new([10, 5, 5, 11, 5], 684),
+            Hand::new([13, 13, 6, 7, 7], 28),
+            Hand::new([13, 10, 11, 11, 10], 220),
+            Hand::new([12, 12, 12, 11, 14], 483),
+        ];
+
+        assert_eq!(hands, expected_hands);
+    }
+
+    #[rstest]
+    #[case("32T3K765", "expected cards and a bid separated by a space")]
+    #[case("32T3 765", "expected 5 cards, got 4")]
+    #[case("32T3KK 765", "expected 5 cards, got 6")]
+    #[case("32T1K 765", "invalid card '1'")]
+    #[case("32T3K 7b5", "invalid bid \"7b5\"")]
+    fn test_parse_hands_invalid_entry(#[case] entry: &str, #[case] expected: &str) {
+        let input = vec![String::from("32T3K 765"), String::from(entry)];
+
+        assert_eq!(
+            parse_hands(&input, &Rules::standard()),
+            Err(ParseError::new("day07", 2, entry, expected))
+        );
+    }
+
+    #[rstest]
+    // Without Jokers
+    #[case([2,2,2,2,2], HandStrength::FiveOfAKind)]
+    #[case([4,4,2,4,4], HandStrength::FourOfAKind)]
+    #[case([4,2,4,4,4], HandStrength::FourOfAKind)]
+    #[case([2,3,2,3,2], HandStrength::FullHouse)]
+    #[case([2,2,3,3,3], HandStrength::FullHouse)]
+    #[case([2,3,4,2,2], HandStrength::ThreeOfAKind)]
+    #[case([2,3,4,3,2], HandStrength::TwoPairs)]
+    #[case([2,3,2,4,5], HandStrength::OnePair)]
+    #[case([2,3,4,5,5], HandStrength::OnePair)]
+    #[case([2,3,4,5,6], HandStrength::HighCard)]
+    // With Jokers
+    #[case([2,2,2,2,0], HandStrength::FiveOfAKind)]
+    #[case([2,2,2,0,0], HandStrength::FiveOfAKind)]
+    #[case([2,2,0,0,0], HandStrength::FiveOfAKind)]
+    #[case([2,0,0,0,0], HandStrength::FiveOfAKind)]
+    #[case([0,0,0,0,0], HandStrength::FiveOfAKind)]
+    #[case([4,4,4,2,0], HandStrength::FourOfAKind)]
+    #[case([4,4,2,0,0], HandStrength::FourOfAKind)]
+    #[case([4,2,0,0,0], HandStrength::FourOfAKind)]
+    #[case([3,3,2,2,0], HandStrength::FullHouse)]
+    #[case([4,4,3,2,0], HandStrength::ThreeOfAKind)]
+    #[case([4,3,2,0,0], HandStrength::ThreeOfAKind)]
+    #[case([5,4,3,2,0], HandStrength::OnePair)]
+    fn test_get_strength(#[case] cards: [u8; 5], #[case] expected: HandStrength) {
+        assert_eq!(HandStrength::from_cards(&cards), expected);
+    }
+
+    #[rstest]
+    #[case(HandStrength::HighCard, "High card")]
+    #[case(HandStrength::TwoPairs, "Two pairs")]
+    #[case(HandStrength::FiveOfAKind, "Five of a kind")]
+    fn test_strength_display(#[case] strength: HandStrength, #[case] expected: &str) {
+        assert_eq!(strength.to_string(), expected);
+    }
+
+    #[rstest]
+    #[case([2, 2, 3, 4, 5], [14, 13, 12, 11, 10], Ordering::Greater)]
+    #[case([2, 2, 3, 4, 5], [2, 2, 3, 4, 6], Ordering::Less)]
+    #[case([0, 2, 3, 4, 5], [2, 2, 3, 4, 5], Ordering::Less)]
+    #[case([13, 13, 6, 7, 7], [13, 13, 6, 7, 7], Ordering::Equal)]
+    fn test_compare(#[case] a: [u8; 5], #[case] b: [u8; 5], #[case] expected: Ordering) {
+        assert_eq!(Hand::new(a, 1).compare(&Hand::new(b, 2)), expected);
+    }
+
+    #[rstest]
+    fn test_get_ranked_hands(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::standard()).unwrap();
+        let sorted = get_sorted_hands(&hands);
+
+        assert_eq!(
+            sorted,
+            vec![
+                &Hand::new([3, 2, 10, 3, 13], 765),
+                &Hand::new([13, 10, 11, 11, 10], 220),
+                &Hand::new([13, 13, 6, 7, 7], 28),
+                &Hand::new([10, 5, 5, 11, 5], 684),
+                &Hand::new([12, 12, 12, 11, 14], 483),
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_get_ranked_hands_with_jokers() {
+        let hands = vec![
+            Hand::new([0, 0, 0, 0, 2], 0),
+            Hand::new([12, 12, 12, 12, 2], 0),
+            Hand::new([0, 13, 13, 13, 2], 0),
+        ];
+        let sorted = get_sorted_hands(&hands);
+
+        assert_eq!(
+            sorted,
+            vec![
+                &Hand::new([0, 13, 13, 13, 2], 0),
+                &Hand::new([12, 12, 12, 12, 2], 0),
+                &Hand::new([0, 0, 0, 0, 2], 0),
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("32T3K 765", Rules::standard())]
+    #[case("KTJJT 220", Rules::standard())]
+    #[case("KTJJT 220", Rules::with_jokers())]
+    #[case("QQQJA 483", Rules::with_jokers())]
+    #[case("QQQJA 483", Rules::new(CARDS, Some('Q')).unwrap())]
+    fn test_format_cards(#[case] entry: &str, #[case] rules: Rules) {
+        let hand = parse_hand(entry, &rules).unwrap();
+
+        assert_eq!(format_cards(&hand.cards, &rules), entry[..5]);
+    }
+
+    #[rstest]
+    #[case(Rules::standard(), "J", 11)]
+    #[case(Rules::with_jokers(), "J", 0)]
+    #[case(Rules::with_jokers(), "2", 2)]
+    #[case(Rules::with_jokers(), "A", 14)]
+    #[case(Rules::new("AKQJT98765432", None).unwrap(), "A", 2)]
+    #[case(Rules::new("AKQJT98765432", None).unwrap(), "2", 14)]
+    #[case(Rules::new(CARDS, Some('Q')).unwrap(), "Q", 0)]
+    #[case(Rules::new(CARDS, Some('Q')).unwrap(), "J", 11)]
+    fn test_get_card_value(#[case] rules: Rules, #[case] card: &str, #[case] expected: u8) {
+        let card = card.chars().next().unwrap();
+
+        assert_eq!(rules.get_card_value(card), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(CARDS, Some('X'), "wildcard 'X' isn't ranked")]
+    #[case("23456789TJQKAA", None, "expected at most 13 cards, got 14")]
+    #[case("23456789TJQK2", None, "duplicate card '2'")]
+    fn test_rules_invalid(
+        #[case] ranking: &str,
+        #[case] wildcard: Option<char>,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(Rules::new(ranking, wildcard), Err(String::from(expected)));
+    }
+
+    #[rstest]
+    #[case(&[], Rules::standard(), Rules::with_jokers())]
+    #[case(&["--wildcard=Q"], Rules::standard(), Rules::new(CARDS, Some('Q')).unwrap())]
+    #[case(
+        &["--ranking=AKQJT98765432", "--wildcard=T"],
+        Rules::new("AKQJT98765432", None).unwrap(),
+        Rules::new("AKQJT98765432", Some('T')).unwrap()
+    )]
+    fn test_parse_rules_args(
+        #[case] args: &[&str],
+        #[case] expected_p1: Rules,
+        #[case] expected_p2: Rules,
+    ) {
+        let args = args.iter().map(|a| a.to_string());
+
+        assert_eq!(parse_rules_args(args), (expected_p1, expected_p2));
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid wildcard, expected a single card: QK")]
+    fn test_parse_rules_args_with_invalid_wildcard() {
+        parse_rules_args(["--wildcard=QK".to_string()]);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid rules: wildcard 'J' isn't ranked")]
+    fn test_parse_rules_args_with_unranked_wildcard() {
+        parse_rules_args(["--ranking=2345".to_string()]);
+    }
+
+    #[rstest]
+    fn test_solve_with_rules(test_input: Vec<String>) {
+        let reversed = Rules::new("AKQJT98765432", None).unwrap();
+        let kings_wild = Rules::new(CARDS, Some('K')).unwrap();
+        let (p1, p2) = solve_with_rules(&test_input, &reversed, &kings_wild).unwrap();
+
+        assert_eq!(p1.to_string(), "6833");
+        assert_eq!(p2.to_string(), "4602");
+    }
+
+    #[rstest]
+    fn test_queens_wild(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::new(CARDS, Some('Q')).unwrap()).unwrap();
+
+        assert_eq!(
+            hands.iter().map(|h| h.strength).collect_vec(),
+            vec![
+                HandStrength::OnePair,
+                HandStrength::ThreeOfAKind,
+                HandStrength::TwoPairs,
+                HandStrength::TwoPairs,
+                HandStrength::FourOfAKind,
+            ]
+        );
+    }
+
+    #[rstest]
+    fn test_render_ranked_hands(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::with_jokers()).unwrap();
+
+        assert_eq!(
+            render_ranked_hands(&hands, &Rules::with_jokers()),
+            [
+                "Rank  Cards  Values          Strength           Bid   Winnings",
+                "   1  32T3K   3  2 10  3 13  One pair           765        765",
+                "   2  KK677  13 13  6  7  7  Two pairs           28         56",
+                "   3  T55J5  10  5  5  0  5  Four of a kind     684       2052",
+                "   4  QQQJA  12 12 12  0 14  Four of a kind     483       1932",
+                "   5  KTJJT  13 10  0  0 10  Four of a kind     220       1100",
+                "Total winnings: 5905",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::standard()).unwrap();
+        let res = get_total_winnings(&hands);
+
+        assert_eq!(res, 6440);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let hands = parse_hands(&test_input, &Rules::with_jokers()).unwrap();
+        let res = get_total_winnings(&hands);
+
+        assert_eq!(res, 5905);
+    }
+
+    fn cards_strategy() -> impl Strategy<Value = [u8; 5]> {
+        prop::array::uniform5(prop_oneof![Just(0u8), 2u8..=14])
+    }
+
+    proptest! {
+        #[test]
+        fn prop_strength_ignores_card_order(cards in cards_strategy(), shift in 0usize..5) {
+            let mut shuffled = cards;
+            shuffled.rotate_left(shift);
+            shuffled.swap(0, 4);
+
+            prop_assert_eq!(HandStrength::from_cards(&shuffled), HandStrength::from_cards(&cards));
+        }
+
+        #[test]
+        fn prop_jokers_make_the_best_hand(cards in cards_strategy(), value in 2u8..=14) {
+            let replaced = cards.map(|c| if c == 0 { value } else { c });
+
+            prop_assert!(HandStrength::from_cards(&cards) >= HandStrength::from_cards(&replaced));
+        }
+
+        #[test]
+        fn prop_compare_is_antisymmetric(a in cards_strategy(), b in cards_strategy()) {
+            let (a, b) = (Hand::new(a, 0), Hand::new(b, 0));
+
+            prop_assert_eq!(a.compare(&b), b.compare(&a).reverse());
+        }
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let hands = parse_hands(&input, &Rules::standard()).unwrap();
+        get_total_winnings(&hands)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let hands = parse_hands(&input, &Rules::with_jokers()).unwrap();
+        get_total_winnings(&hands)
+    }
+
+    aoc_common::answer_tests!("day07", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day07", part1, part2);
+
+    aoc_common::perf_test!("day07", 5, puzzle_input(), solve);
+}
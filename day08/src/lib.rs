@@ -0,0 +1,465 @@
+use inpt::{inpt, Inpt};
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::viz::Color;
+use aoc_common::{format_duration, InputSource, ParseError};
+
+/// Where the network is written with `--export dot`.
+const DOT_PATH: &str = "day08.dot";
+
+/// The colors of the ghosts' cycles in the DOT export, reused if there are more ghosts.
+const GHOST_COLORS: [Color; 6] = [
+    Color::Blue,
+    Color::Magenta,
+    Color::Cyan,
+    Color::Yellow,
+    Color::Rgb(255, 128, 0),
+    Color::Rgb(128, 0, 255),
+];
+
+/// Solve the puzzle input and print the answers, as the `day08` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day08.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.windows(2).any(|a| a[0] == "--export" && a[1] == "dot") {
+        let map = parse_network_map(&input).unwrap_or_else(|e| panic!("{}", e));
+        std::fs::write(DOT_PATH, render_dot(&map))
+            .unwrap_or_else(|e| panic!("Unable to write {}: {}", DOT_PATH, e));
+        println!("Network written to {}", DOT_PATH);
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let map = parse_network_map(input)?;
+
+    let p1 = follow_map(&map);
+    let p2 = follow_map_parallel(&map);
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Direction {
+    Left,
+    Right,
+}
+
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'L' => Ok(Direction::Left),
+            'R' => Ok(Direction::Right),
+            _ => Err(format!("invalid direction {:?}", value)),
+        }
+    }
+}
+
+type NodeId = u16;
+
+/// The network, with nodes referenced by their index. The left and right successors of node `i`
+/// are `next_left[i]` and `next_right[i]`.
+#[derive(Debug, PartialEq)]
+struct NetworkMap {
+    directions: Vec<Direction>,
+    names: Vec<String>,
+    next_left: Vec<NodeId>,
+    next_right: Vec<NodeId>,
+}
+
+impl NetworkMap {
+    fn get_node_id(&self, name: &str) -> Option<NodeId> {
+        self.names
+            .iter()
+            .position(|n| n == name)
+            .map(|idx| idx as NodeId)
+    }
+}
+
+#[derive(Debug, PartialEq, Inpt)]
+#[inpt(regex = r"([0-9A-Z]{3}) = \(([0-9A-Z]{3}), ([0-9A-Z]{3})\)")]
+struct Node {
+    name: String,
+    next_left: String,
+    next_right: String,
+}
+
+fn parse_network_map(input: &[String]) -> Result<NetworkMap, ParseError> {
+    let first = input.first().map_or("", String::as_str);
+    let directions = first
+        .chars()
+        .map(Direction::try_from)
+        .collect::<Result<_, _>>()
+        .map_err(|e| ParseError::new("day08", 1, first, e))?;
+
+    if let Some(line) = input.get(1).filter(|l| !l.is_empty()) {
+        return Err(ParseError::new("day08", 2, line, "expected an empty line"));
+    }
+
+    let nodes: Vec<Node> = input
+        .iter()
+        .enumerate()
+        .skip(2)
+        .map(|(idx, n)| {
+            inpt::<Node>(n).map_err(|_| {
+                ParseError::new("day08", idx + 1, n, "expected 'AAA = (BBB, CCC)' node")
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    assert!(nodes.len() <= NodeId::MAX as usize, "Too many nodes");
+
+    let ids: HashMap<&str, NodeId> = nodes
+        .iter()
+        .enumerate()
+        .map(|(idx, n)| (n.name.as_str(), idx as NodeId))
+        .collect();
+
+    // Nodes are listed from the third line of the input.
+    let get_id = |idx: usize, name: &str| {
+        ids.get(name).copied().ok_or_else(|| {
+            ParseError::new(
+                "day08",
+                idx + 3,
+                &input[idx + 2],
+                format!("unknown node {:?}", name),
+            )
+        })
+    };
+
+    Ok(NetworkMap {
+        directions,
+        next_left: nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, n)| get_id(idx, &n.next_left))
+            .collect::<Result<_, _>>()?,
+        next_right: nodes
+            .iter()
+            .enumerate()
+            .map(|(idx, n)| get_id(idx, &n.next_right))
+            .collect::<Result<_, _>>()?,
+        names: nodes.into_iter().map(|n| n.name).collect(),
+    })
+}
+
+fn follow_map(map: &NetworkMap) -> u64 {
+    let start = map.get_node_id("AAA").expect("Unable to find start node");
+    let end = map.get_node_id("ZZZ").expect("Unable to find end node");
+
+    get_steps_to_end(start, map, |n| n == end)
+}
+
+fn follow_map_parallel(map: &NetworkMap) -> u64 {
+    let is_end: Vec<bool> = map.names.iter().map(|n| n.ends_with('Z')).collect();
+
+    map.names
+        .iter()
+        .enumerate()
+        .filter(|(_, n)| n.ends_with('A'))
+        .map(|(idx, _)| get_steps_to_end(idx as NodeId, map, |n| is_end[n as usize]))
+        .fold(1, num::integer::lcm)
+}
+
+fn get_steps_to_end<F>(start_node: NodeId, map: &NetworkMap, has_reached_end: F) -> u64
+where
+    F: Fn(NodeId) -> bool,
+{
+    let mut current = start_node;
+
+    for (step, dir) in map.directions.iter().cycle().enumerate() {
+        current = match dir {
+            Direction::Left => map.next_left[current as usize],
+            Direction::Right => map.next_right[current as usize],
+        };
+
+        if has_reached_end(current) {
+            return (step + 1) as u64;
+        }
+    }
+
+    unreachable!("you shouldn't be here");
+}
+
+/// Where a ghost ends up looping. Since there are finitely many (node, direction index) states,
+/// every ghost eventually enters a cycle of `length` steps starting at step `start`.
+#[derive(Debug, PartialEq)]
+struct GhostCycle {
+    start: u64,
+    length: u64,
+    /// The first end node reached and the step at which it's reached.
+    first_end: Option<(NodeId, u64)>,
+    /// The nodes and directions of the steps taken in the cycle.
+    steps: Vec<(NodeId, usize)>,
+}
+
+fn get_ghost_cycle(start_node: NodeId, map: &NetworkMap) -> GhostCycle {
+    let mut seen: HashMap<(NodeId, usize), u64> = HashMap::new();
+    let mut states = Vec::new();
+    let mut first_end = None;
+    let mut current = start_node;
+
+    for (step, dir_idx) in (0..map.directions.len()).cycle().enumerate() {
+        let step = step as u64;
+
+        if let Some(&start) = seen.get(&(current, dir_idx)) {
+            return GhostCycle {
+                start,
+                length: step - start,
+                first_end,
+                steps: states.split_off(start as usize),
+            };
+        }
+
+        if first_end.is_none() && map.names[current as usize].ends_with('Z') {
+            first_end = Some((current, step));
+        }
+
+        seen.insert((current, dir_idx), step);
+        states.push((current, dir_idx));
+
+        current = match map.directions[dir_idx] {
+            Direction::Left => map.next_left[current as usize],
+            Direction::Right => map.next_right[current as usize],
+        };
+    }
+
+    unreachable!("you shouldn't be here");
+}
+
+/// Draw the network as a Graphviz graph, with the start and end nodes highlighted and the cycle
+/// of each ghost drawn in its own color. The start nodes are labeled with where their ghost
+/// first reaches an end and how long its cycle is.
+fn render_dot(map: &NetworkMap) -> String {
+    let mut dot = String::from("digraph network {\n");
+    let mut cycle_edges: HashMap<(NodeId, usize), Color> = HashMap::new();
+
+    for (id, name) in map.names.iter().enumerate() {
+        let id = id as NodeId;
+
+        let attrs = if name.ends_with('A') {
+            let cycle = get_ghost_cycle(id, map);
+            let ghost = map.names[..id as usize]
+                .iter()
+                .filter(|n| n.ends_with('A'))
+                .count();
+            let color = GHOST_COLORS[ghost % GHOST_COLORS.len()];
+
+            for &(node, dir_idx) in &cycle.steps {
+                let dir = &map.directions[dir_idx];
+                cycle_edges.insert((node, (*dir == Direction::Right) as usize), color);
+            }
+
+            let end = match cycle.first_end {
+                Some((end, step)) => {
+                    format!("reaches {} at step {}", map.names[end as usize], step)
+                }
+                None => "never reaches an end".to_string(),
+            };
+
+            let label = format!(
+                "{}\\n{}\\ncycle of {} steps from step {}",
+                name, end, cycle.length, cycle.start
+            );
+
+            format!(
+                "label=\"{}\", style=filled, fillcolor=\"{}\"",
+                label,
+                Color::Green.hex()
+            )
+        } else if name.ends_with('Z') {
+            format!("style=filled, fillcolor=\"{}\"", Color::Red.hex())
+        } else {
+            continue;
+        };
+
+        dot.push_str(&format!("  \"{}\" [{}];\n", name, attrs));
+    }
+
+    for (id, name) in map.names.iter().enumerate() {
+        let next = [map.next_left[id], map.next_right[id]];
+
+        for (side, (label, next)) in ["L", "R"].iter().zip(next).enumerate() {
+            let attrs = match cycle_edges.get(&(id as NodeId, side)) {
+                Some(&color) => format!(", color=\"{}\", penwidth=2", color.hex()),
+                None => String::new(),
+            };
+
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}\"{}];\n",
+                name, map.names[next as usize], label, attrs
+            ));
+        }
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(test_input_p1, day = 8, variant = "p1");
+
+    aoc_common::example_fixture!(test_input_p1_alternate, day = 8, variant = "p1_alternate");
+
+    aoc_common::example_fixture!(test_input_p2, day = 8, variant = "p2");
+
+    aoc_common::puzzle_fixture!("day08.txt");
+
+    #[rstest]
+    fn test_parse_network_map(test_input_p1: Vec<String>) {
+        let map = parse_network_map(&test_input_p1).unwrap();
+
+        assert_eq!(
+            map,
+            NetworkMap {
+                directions: vec![Direction::Right, Direction::Left],
+                names: vec![
+                    "AAA".to_string(),
+                    "BBB".to_string(),
+                    "CCC".to_string(),
+                    "DDD".to_string(),
+                    "EEE".to_string(),
+                    "GGG".to_string(),
+                    "ZZZ".to_string(),
+                ],
+                next_left: vec![1, 3, 6, 3, 4, 5, 6],
+                next_right: vec![2, 4, 5, 3, 4, 5, 6],
+            }
+        );
+    }
+
+    #[rstest]
+    #[case(0, "RLX", "invalid direction 'X'")]
+    #[case(1, "AAA = (BBB, CCC)", "expected an empty line")]
+    #[case(3, "BBB = (DDD EEE)", "expected 'AAA = (BBB, CCC)' node")]
+    #[case(4, "CCC = (ZZZ, XYZ)", "unknown node \"XYZ\"")]
+    fn test_parse_network_map_invalid(
+        test_input_p1: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input_p1;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_network_map(&input),
+            Err(ParseError::new("day08", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    #[case(test_input_p1(), 2)]
+    #[case(test_input_p1_alternate(), 6)]
+    fn test_p1(#[case] input: Vec<String>, #[case] expected: u64) {
+        let map = parse_network_map(&input).unwrap();
+        let steps = follow_map(&map);
+
+        assert_eq!(steps, expected);
+    }
+
+    #[rstest]
+    fn test_p2(test_input_p2: Vec<String>) {
+        let map = parse_network_map(&test_input_p2).unwrap();
+        let steps = follow_map_parallel(&map);
+
+        assert_eq!(steps, 6);
+    }
+
+    #[rstest]
+    fn test_get_ghost_cycle(test_input_p2: Vec<String>) {
+        let map = parse_network_map(&test_input_p2).unwrap();
+
+        assert_eq!(
+            get_ghost_cycle(0, &map),
+            GhostCycle {
+                start: 1,
+                length: 2,
+                first_end: Some((2, 2)),
+                steps: vec![(1, 1), (2, 0)],
+            }
+        );
+        assert_eq!(
+            get_ghost_cycle(3, &map),
+            GhostCycle {
+                start: 1,
+                length: 6,
+                first_end: Some((6, 3)),
+                steps: vec![(4, 1), (5, 0), (6, 1), (4, 0), (5, 1), (6, 0)],
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_get_ghost_cycle_full_input() {
+        aoc_common::require_input!("day08.txt");
+        let puzzle_input = puzzle_input();
+        let map = parse_network_map(&puzzle_input).unwrap();
+
+        // The LCM of the steps to the first end only gives the answer because every ghost gets
+        // back to its end exactly as many steps later, over and over.
+        for (id, _) in map
+            .names
+            .iter()
+            .enumerate()
+            .filter(|(_, n)| n.ends_with('A'))
+        {
+            let cycle = get_ghost_cycle(id as NodeId, &map);
+            let (end, step) = cycle.first_end.unwrap();
+
+            assert_eq!(step % cycle.length, 0);
+            assert_eq!(
+                cycle
+                    .steps
+                    .iter()
+                    .filter(|&&(n, _)| map.names[n as usize].ends_with('Z'))
+                    .collect::<Vec<_>>(),
+                vec![&(end, (step as usize) % map.directions.len())]
+            );
+        }
+    }
+
+    #[rstest]
+    fn test_render_dot(test_input_p2: Vec<String>) {
+        let map = parse_network_map(&test_input_p2).unwrap();
+
+        insta::assert_snapshot!(render_dot(&map));
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let map = parse_network_map(&input).unwrap();
+        follow_map(&map)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let map = parse_network_map(&input).unwrap();
+        follow_map_parallel(&map)
+    }
+
+    aoc_common::answer_tests!("day08", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day08", part1, part2);
+
+    aoc_common::perf_test!("day08", 5, puzzle_input(), solve);
+}
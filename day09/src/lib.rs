@@ -0,0 +1,380 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day09` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day09.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if std::env::args().any(|a| a == "--closed-form") {
+        let start = Instant::now();
+        let (r1, r2) = solve_closed_form(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+        let t = start.elapsed().as_nanos();
+
+        println!();
+        println!("Part 1 (closed form): {}", r1);
+        println!("Part 2 (closed form): {}", r2);
+        println!("Duration: {}", format_duration(t));
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let oasis = parse_oasis(input)?;
+
+    let p1 = get_sum_of_next_values(&oasis);
+    let p2 = get_sum_of_previous_values(&oasis);
+
+    Ok((p1, p2))
+}
+
+/// Solve both parts with the closed-form extrapolation, without building the difference pyramids,
+/// to compare both approaches with `--closed-form`.
+fn solve_closed_form(input: &[String]) -> Result<(i64, i64), ParseError> {
+    let oasis = parse_values(input)?;
+
+    let p1 = oasis.iter().map(|v| extrapolate_lagrange(v)).sum();
+    let p2 = oasis
+        .iter()
+        .map(|v| extrapolate_backwards_lagrange(v))
+        .sum();
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Sequence {
+    values: Vec<i64>,
+    decrements: Vec<i64>,
+    increments: Vec<i64>,
+}
+
+impl Sequence {
+    fn new(values: Vec<i64>) -> Self {
+        let mut decrements = vec![*values.first().unwrap()];
+        let mut increments = vec![*values.last().unwrap()];
+
+        let mut deltas: Vec<i64> = values[..values.len() - 1]
+            .iter()
+            .zip(&values[1..])
+            .map(|(a, b)| b - a)
+            .collect();
+
+        loop {
+            decrements.insert(0, *deltas.first().unwrap());
+            increments.insert(0, *deltas.last().unwrap());
+
+            deltas = deltas[..deltas.len() - 1]
+                .iter()
+                .zip(&deltas[1..])
+                .map(|(a, b)| b - a)
+                .collect();
+
+            if deltas.iter().all(|&i| i == 0) {
+                decrements.insert(0, 0);
+                increments.insert(0, 0);
+                break;
+            }
+        }
+
+        Self {
+            values,
+            increments,
+            decrements,
+        }
+    }
+
+    fn extrapolate(&self) -> i64 {
+        self.increments.iter().sum()
+    }
+
+    fn extrapolate_backwards(&self) -> i64 {
+        self.decrements.iter().fold(0, |acc, v| v - acc)
+    }
+}
+
+/// Closed-form extrapolation. A sequence of `n` values whose differences eventually become zero
+/// is a polynomial of degree below `n`, so by Lagrange interpolation on equally spaced points its
+/// next value is `sum((-1)^(n-1-i) * C(n, i) * x_i)`, with no difference pyramid to build.
+fn extrapolate_lagrange(values: &[i64]) -> i64 {
+    let n = values.len();
+
+    binomial_coefficients(n)
+        .iter()
+        .zip(values)
+        .enumerate()
+        .map(|(i, (c, v))| {
+            if (n - 1 - i).is_multiple_of(2) {
+                c * v
+            } else {
+                -c * v
+            }
+        })
+        .sum()
+}
+
+/// Closed-form backward extrapolation: the previous value of a sequence of `n` values is
+/// `sum((-1)^i * C(n, i+1) * x_i)`, as in [`extrapolate_lagrange`].
+fn extrapolate_backwards_lagrange(values: &[i64]) -> i64 {
+    let n = values.len();
+
+    binomial_coefficients(n)
+        .iter()
+        .skip(1)
+        .zip(values)
+        .enumerate()
+        .map(|(i, (c, v))| if i.is_multiple_of(2) { c * v } else { -c * v })
+        .sum()
+}
+
+/// Get the binomial coefficients `C(n, 0)` to `C(n, n)`.
+fn binomial_coefficients(n: usize) -> Vec<i64> {
+    let mut coefficients = vec![1i64; n + 1];
+
+    for k in 1..=n {
+        coefficients[k] = coefficients[k - 1] * (n - k + 1) as i64 / k as i64;
+    }
+
+    coefficients
+}
+
+fn parse_values(input: &[String]) -> Result<Vec<Vec<i64>>, ParseError> {
+    parse_lines("day09", input, |entry| {
+        let values: Vec<i64> = entry
+            .split_whitespace()
+            .map(|v| v.parse().map_err(|_| format!("invalid number {:?}", v)))
+            .collect::<Result<_, _>>()?;
+
+        if values.is_empty() {
+            return Err(String::from("expected at least one number"));
+        }
+
+        Ok(values)
+    })
+}
+
+fn parse_oasis(input: &[String]) -> Result<Vec<Sequence>, ParseError> {
+    Ok(parse_values(input)?
+        .into_iter()
+        .map(Sequence::new)
+        .collect())
+}
+
+fn get_sum_of_next_values(oasis: &[Sequence]) -> i64 {
+    oasis.iter().map(|s| s.extrapolate()).sum()
+}
+
+fn get_sum_of_previous_values(oasis: &[Sequence]) -> i64 {
+    oasis.iter().map(|s| s.extrapolate_backwards()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 9);
+
+    aoc_common::puzzle_fixture!("day09.txt");
+
+    #[rstest]
+    fn test_sequence_new() {
+        let values = vec![10, 13, 16, 21, 30, 45];
+        let seq = Sequence::new(values);
+
+        assert_eq!(
+            seq,
+            Sequence {
+                values: vec![10, 13, 16, 21, 30, 45],
+                decrements: vec![0, 2, 0, 3, 10],
+                increments: vec![0, 2, 6, 15, 45],
+            }
+        );
+    }
+
+    #[rstest]
+    fn test_sequence_extrapolate() {
+        let values = vec![10, 13, 16, 21, 30, 45];
+
+        let seq = Sequence::new(values);
+
+        let prediction = seq.extrapolate();
+
+        assert_eq!(prediction, 68);
+    }
+
+    #[rstest]
+    fn test_sequence_extrapolate_backwards() {
+        let values = vec![10, 13, 16, 21, 30, 45];
+
+        let seq = Sequence::new(values);
+
+        let prediction = seq.extrapolate_backwards();
+
+        assert_eq!(prediction, 5);
+    }
+
+    #[rstest]
+    #[case(0, vec![1])]
+    #[case(1, vec![1, 1])]
+    #[case(4, vec![1, 4, 6, 4, 1])]
+    #[case(6, vec![1, 6, 15, 20, 15, 6, 1])]
+    fn test_binomial_coefficients(#[case] n: usize, #[case] expected: Vec<i64>) {
+        assert_eq!(binomial_coefficients(n), expected);
+    }
+
+    #[rstest]
+    fn test_extrapolate_lagrange(test_input: Vec<String>) {
+        let oasis = parse_values(&test_input).unwrap();
+
+        let next: Vec<i64> = oasis.iter().map(|v| extrapolate_lagrange(v)).collect();
+        let previous: Vec<i64> = oasis
+            .iter()
+            .map(|v| extrapolate_backwards_lagrange(v))
+            .collect();
+
+        assert_eq!(next, vec![18, 28, 68]);
+        assert_eq!(previous, vec![-3, 0, 5]);
+    }
+
+    /// Check that the difference pyramids and the closed form agree on every sequence.
+    fn assert_extrapolations_agree(input: &[String]) {
+        let oasis = parse_oasis(input).unwrap();
+
+        aoc_common::assert_variants_agree(&[
+            ("difference pyramids", &|| {
+                oasis
+                    .iter()
+                    .map(|s| (s.extrapolate(), s.extrapolate_backwards()))
+                    .collect::<Vec<_>>()
+            }),
+            ("closed form", &|| {
+                oasis
+                    .iter()
+                    .map(|s| {
+                        (
+                            extrapolate_lagrange(&s.values),
+                            extrapolate_backwards_lagrange(&s.values),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            }),
+        ]);
+    }
+
+    #[rstest]
+    fn test_variants_agree(test_input: Vec<String>) {
+        assert_extrapolations_agree(&test_input);
+    }
+
+    #[rstest]
+    fn test_variants_agree_full_input() {
+        aoc_common::require_input!("day09.txt");
+
+        assert_extrapolations_agree(&puzzle_input());
+    }
+
+    #[rstest]
+    fn test_solve_closed_form(test_input: Vec<String>) {
+        assert_eq!(solve_closed_form(&test_input), Ok((114, 2)));
+    }
+
+    #[rstest]
+    fn test_parse_oasis(test_input: Vec<String>) {
+        let oasis = parse_oasis(&test_input).unwrap();
+
+        assert_eq!(
+            oasis,
+            vec![
+                Sequence {
+                    values: vec![0, 3, 6, 9, 12, 15],
+                    decrements: vec![0, 3, 0],
+                    increments: vec![0, 3, 15],
+                },
+                Sequence {
+                    values: vec![1, 3, 6, 10, 15, 21],
+                    decrements: vec![0, 1, 2, 1],
+                    increments: vec![0, 1, 6, 21],
+                },
+                Sequence {
+                    values: vec![10, 13, 16, 21, 30, 45],
+                    decrements: vec![0, 2, 0, 3, 10],
+                    increments: vec![0, 2, 6, 15, 45],
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("0 3 6 9 l2 15", "invalid number \"l2\"")]
+    #[case("", "expected at least one number")]
+    fn test_parse_oasis_invalid_entry(#[case] entry: &str, #[case] expected: &str) {
+        let input = vec![String::from("0 3 6 9 12 15"), String::from(entry)];
+
+        assert_eq!(
+            parse_oasis(&input),
+            Err(ParseError::new("day09", 2, entry, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let oasis = parse_oasis(&test_input).unwrap();
+        let res = get_sum_of_next_values(&oasis);
+
+        assert_eq!(res, 114);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let oasis = parse_oasis(&test_input).unwrap();
+        let res = get_sum_of_previous_values(&oasis);
+
+        assert_eq!(res, 2);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let oasis = parse_oasis(&input).unwrap();
+        get_sum_of_next_values(&oasis)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let oasis = parse_oasis(&input).unwrap();
+        get_sum_of_previous_values(&oasis)
+    }
+
+    aoc_common::answer_tests!("day09", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day09", part1, part2);
+
+    aoc_common::perf_test!("day09", 5, puzzle_input(), solve);
+
+    #[test]
+    fn test_solve_closed_form_performance() {
+        if !aoc_common::perf_tests_enabled() {
+            return;
+        }
+        aoc_common::require_input!("day09.txt");
+
+        let input = puzzle_input();
+        aoc_common::assert_within_budget(
+            "day09 (closed form)",
+            std::time::Duration::from_millis(5),
+            || {
+                let _ = std::hint::black_box(solve_closed_form(&input));
+            },
+        );
+    }
+}
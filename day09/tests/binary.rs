@@ -0,0 +1 @@
+aoc_common::binary_tests!("day09");
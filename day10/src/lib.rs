@@ -0,0 +1,675 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use itertools::Itertools;
+
+use aoc_common::dump::dump_parsed_flag;
+use aoc_common::viz::Color;
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError, Point};
+use serde::Serialize;
+
+/// Where the map is drawn with `--visualize svg`.
+const SVG_PATH: &str = "day10.svg";
+
+/// Solve the puzzle input and print the answers, as the `day10` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day10.txt").read();
+
+    if let Some(format) = dump_parsed_flag() {
+        let map = parse_map(&input).unwrap_or_else(|e| panic!("{}", e));
+        print!("{}", format.dump(&map));
+        return;
+    }
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args
+        .windows(2)
+        .any(|a| a[0] == "--visualize" && a[1] == "svg")
+    {
+        let map = parse_map(&input).unwrap_or_else(|e| panic!("{}", e));
+        std::fs::write(SVG_PATH, render_svg(&map))
+            .unwrap_or_else(|e| panic!("Unable to write {}: {}", SVG_PATH, e));
+        println!("Map written to {}", SVG_PATH);
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let map = parse_map(input)?;
+
+    let p1 = get_farthest_from_start(&map);
+    let p2 = get_tiles_in_loop(&map);
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+enum Tile {
+    Start,
+    Ground,
+    PipeNS,
+    PipeEW,
+    PipeNE,
+    PipeNW,
+    PipeSE,
+    PipeSW,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'S' => Ok(Self::Start),
+            '.' => Ok(Self::Ground),
+            '|' => Ok(Self::PipeNS),
+            '-' => Ok(Self::PipeEW),
+            'L' => Ok(Self::PipeNE),
+            'J' => Ok(Self::PipeNW),
+            'F' => Ok(Self::PipeSE),
+            '7' => Ok(Self::PipeSW),
+            _ => Err(format!("invalid tile {:?}", value)),
+        }
+    }
+}
+
+impl Tile {
+    fn to_char(self) -> char {
+        match self {
+            Self::Start => 'S',
+            Self::Ground => '.',
+            Self::PipeNS => '|',
+            Self::PipeEW => '-',
+            Self::PipeNE => 'L',
+            Self::PipeNW => 'J',
+            Self::PipeSE => 'F',
+            Self::PipeSW => '7',
+        }
+    }
+}
+
+type TileGrid = Vec<Vec<Tile>>;
+type Position = Point<usize>;
+
+type Successors = [Option<Position>; 2];
+
+#[derive(Debug, PartialEq, Serialize)]
+struct TileMap {
+    height: usize,
+    width: usize,
+    start: Position,
+    tiles: TileGrid,
+    /// The connected neighbors of each tile, indexed by `x * width + y`.
+    successors: Vec<Successors>,
+}
+
+impl TileMap {
+    fn get_successors(&self, pos: &Position) -> Successors {
+        self.successors[pos.x * self.width + pos.y]
+    }
+}
+
+fn find_successors(tiles: &TileGrid, pos: &Position) -> Successors {
+    let x = pos.x;
+    let y = pos.y;
+    let height = tiles.len();
+    let width = tiles[0].len();
+    let cur = tiles[x][y];
+    let mut edges = [None; 2];
+    let mut n = 0;
+
+    let mut push = |p: Position| {
+        if n == 2 {
+            panic!("Too many successors!")
+        }
+        edges[n] = Some(p);
+        n += 1;
+    };
+
+    if x > 0 && is_walkable(cur, tiles[x - 1][y], Direction::Up) {
+        push(Point { x: x - 1, y });
+    }
+    if x < height - 1 && is_walkable(cur, tiles[x + 1][y], Direction::Down) {
+        push(Point { x: x + 1, y });
+    }
+    if y > 0 && is_walkable(cur, tiles[x][y - 1], Direction::Left) {
+        push(Point { x, y: y - 1 });
+    }
+    if y < width - 1 && is_walkable(cur, tiles[x][y + 1], Direction::Right) {
+        push(Point { x, y: y + 1 });
+    }
+
+    edges
+}
+
+#[inline]
+fn is_walkable(current: Tile, target: Tile, direction: Direction) -> bool {
+    if target == Tile::Ground {
+        return false;
+    }
+
+    #[allow(clippy::match_like_matches_macro)]
+    match (current, direction, target) {
+        (Tile::PipeNS, Direction::Up, Tile::PipeNS) => true,
+        (Tile::PipeNS, Direction::Up, Tile::PipeSE) => true,
+        (Tile::PipeNS, Direction::Up, Tile::PipeSW) => true,
+        (Tile::PipeNS, Direction::Down, Tile::PipeNS) => true,
+        (Tile::PipeNS, Direction::Down, Tile::PipeNE) => true,
+        (Tile::PipeNS, Direction::Down, Tile::PipeNW) => true,
+
+        (Tile::PipeEW, Direction::Left, Tile::PipeEW) => true,
+        (Tile::PipeEW, Direction::Left, Tile::PipeSE) => true,
+        (Tile::PipeEW, Direction::Left, Tile::PipeNE) => true,
+        (Tile::PipeEW, Direction::Right, Tile::PipeEW) => true,
+        (Tile::PipeEW, Direction::Right, Tile::PipeNW) => true,
+        (Tile::PipeEW, Direction::Right, Tile::PipeSW) => true,
+
+        (Tile::PipeNE, Direction::Up, Tile::PipeNS) => true,
+        (Tile::PipeNE, Direction::Up, Tile::PipeSE) => true,
+        (Tile::PipeNE, Direction::Up, Tile::PipeSW) => true,
+        (Tile::PipeNE, Direction::Right, Tile::PipeEW) => true,
+        (Tile::PipeNE, Direction::Right, Tile::PipeNW) => true,
+        (Tile::PipeNE, Direction::Right, Tile::PipeSW) => true,
+
+        (Tile::PipeNW, Direction::Up, Tile::PipeNS) => true,
+        (Tile::PipeNW, Direction::Up, Tile::PipeSE) => true,
+        (Tile::PipeNW, Direction::Up, Tile::PipeSW) => true,
+        (Tile::PipeNW, Direction::Left, Tile::PipeEW) => true,
+        (Tile::PipeNW, Direction::Left, Tile::PipeSE) => true,
+        (Tile::PipeNW, Direction::Left, Tile::PipeNE) => true,
+
+        (Tile::PipeSE, Direction::Down, Tile::PipeNS) => true,
+        (Tile::PipeSE, Direction::Down, Tile::PipeNE) => true,
+        (Tile::PipeSE, Direction::Down, Tile::PipeNW) => true,
+        (Tile::PipeSE, Direction::Right, Tile::PipeEW) => true,
+        (Tile::PipeSE, Direction::Right, Tile::PipeNW) => true,
+        (Tile::PipeSE, Direction::Right, Tile::PipeSW) => true,
+
+        (Tile::PipeSW, Direction::Down, Tile::PipeNS) => true,
+        (Tile::PipeSW, Direction::Down, Tile::PipeNE) => true,
+        (Tile::PipeSW, Direction::Down, Tile::PipeNW) => true,
+        (Tile::PipeSW, Direction::Left, Tile::PipeEW) => true,
+        (Tile::PipeSW, Direction::Left, Tile::PipeSE) => true,
+        (Tile::PipeSW, Direction::Left, Tile::PipeNE) => true,
+
+        _ => false,
+    }
+}
+
+fn parse_map(input: &[String]) -> Result<TileMap, ParseError> {
+    let width = input.first().map_or(0, |l| l.len());
+    let mut tiles: TileGrid = parse_lines("day10", input, |line| {
+        if line.len() != width {
+            return Err(format!("expected {} columns, got {}", width, line.len()));
+        }
+
+        line.chars().map(Tile::try_from).collect()
+    })?;
+
+    let height = input.len();
+    let start = get_start(&tiles);
+
+    for tile in [
+        Tile::PipeNS,
+        Tile::PipeEW,
+        Tile::PipeNE,
+        Tile::PipeNW,
+        Tile::PipeSE,
+        Tile::PipeSW,
+    ] {
+        tiles[start.x][start.y] = tile;
+
+        if find_successors(&tiles, &start).iter().all(Option::is_some) {
+            break;
+        }
+    }
+
+    let successors = (0..height)
+        .flat_map(|x| (0..width).map(move |y| Position::new(x, y)))
+        .map(|p| find_successors(&tiles, &p))
+        .collect();
+
+    Ok(TileMap {
+        height,
+        width,
+        start,
+        tiles,
+        successors,
+    })
+}
+
+fn get_start(tiles: &TileGrid) -> Position {
+    for (x, row) in tiles.iter().enumerate() {
+        for (y, value) in row.iter().enumerate() {
+            if *value == Tile::Start {
+                return Point { x, y };
+            }
+        }
+    }
+
+    panic!("Start not found");
+}
+
+/// Walk the main loop from the start, returning its tiles in the order they are traversed.
+fn get_main_loop(map: &TileMap) -> Vec<Position> {
+    let mut path_loop = vec![map.start];
+    let mut prev = map.start;
+    let mut cur = map.get_successors(&map.start)[0].expect("No loop found");
+
+    while cur != map.start {
+        path_loop.push(cur);
+
+        let next = match map.get_successors(&cur) {
+            [Some(a), Some(b)] => {
+                if a == prev {
+                    b
+                } else {
+                    a
+                }
+            }
+            _ => panic!("Loop is broken"),
+        };
+
+        prev = cur;
+        cur = next;
+    }
+
+    path_loop
+}
+
+fn get_farthest_from_start(map: &TileMap) -> usize {
+    get_main_loop(map).len() / 2
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Region {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// The tile map with each tile tagged by its position relative to the main loop.
+struct ClassifiedMap {
+    tiles: Vec<Vec<(Tile, Region)>>,
+}
+
+impl ClassifiedMap {
+    fn count(&self, region: Region) -> usize {
+        self.tiles
+            .iter()
+            .flatten()
+            .filter(|(_, r)| *r == region)
+            .count()
+    }
+}
+
+impl Display for ClassifiedMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in &self.tiles {
+            let line: String = row
+                .iter()
+                .map(|&(tile, region)| match region {
+                    Region::Loop => tile.to_char(),
+                    Region::Inside => 'I',
+                    Region::Outside => 'O',
+                })
+                .collect();
+
+            writeln!(f, "{}", line)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Classify every tile with a ray-casting parity scan: walking each row from left to right, every
+/// loop tile with a connection to the north toggles whether we are inside the loop. This handles
+/// `|` as well as the `L..7` and `F..J` crossings, since only one tile of each of those pairs
+/// connects north.
+fn classify_tiles(map: &TileMap) -> ClassifiedMap {
+    let mut in_loop = vec![vec![false; map.width]; map.height];
+    for p in get_main_loop(map) {
+        in_loop[p.x][p.y] = true;
+    }
+
+    let tiles = map
+        .tiles
+        .iter()
+        .enumerate()
+        .map(|(x, row)| {
+            let mut inside = false;
+
+            row.iter()
+                .enumerate()
+                .map(|(y, &tile)| {
+                    let region = if in_loop[x][y] {
+                        if matches!(tile, Tile::PipeNS | Tile::PipeNE | Tile::PipeNW) {
+                            inside = !inside;
+                        }
+                        Region::Loop
+                    } else if inside {
+                        Region::Inside
+                    } else {
+                        Region::Outside
+                    };
+
+                    (tile, region)
+                })
+                .collect()
+        })
+        .collect();
+
+    ClassifiedMap { tiles }
+}
+
+/// The size of a tile in the SVG rendering, in pixels.
+const SVG_TILE_SIZE: usize = 10;
+
+/// Draw the map as an SVG image: the tiles inside and outside of the main loop as squares of
+/// different colors, the loop as a line through the center of its tiles, and the start as a dot.
+fn render_svg(map: &TileMap) -> String {
+    let classified = classify_tiles(map);
+    let center = |p: &Position| {
+        (
+            p.y * SVG_TILE_SIZE + SVG_TILE_SIZE / 2,
+            p.x * SVG_TILE_SIZE + SVG_TILE_SIZE / 2,
+        )
+    };
+
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\">\n",
+        map.width * SVG_TILE_SIZE,
+        map.height * SVG_TILE_SIZE
+    );
+
+    for (x, row) in classified.tiles.iter().enumerate() {
+        for (y, &(_, region)) in row.iter().enumerate() {
+            let color = match region {
+                Region::Loop => continue,
+                Region::Inside => Color::Green,
+                Region::Outside => Color::Gray,
+            };
+
+            svg.push_str(&format!(
+                "  <rect x=\"{}\" y=\"{}\" width=\"{size}\" height=\"{size}\" fill=\"{}\"/>\n",
+                y * SVG_TILE_SIZE,
+                x * SVG_TILE_SIZE,
+                color.hex(),
+                size = SVG_TILE_SIZE,
+            ));
+        }
+    }
+
+    let points = get_main_loop(map)
+        .iter()
+        .map(|p| {
+            let (cx, cy) = center(p);
+            format!("{},{}", cx, cy)
+        })
+        .join(" ");
+    svg.push_str(&format!(
+        "  <polygon points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"2\"/>\n",
+        points,
+        Color::Yellow.hex()
+    ));
+
+    let (cx, cy) = center(&map.start);
+    svg.push_str(&format!(
+        "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+        cx,
+        cy,
+        SVG_TILE_SIZE / 3,
+        Color::Red.hex()
+    ));
+
+    svg.push_str("</svg>\n");
+
+    svg
+}
+
+fn get_tiles_in_loop(map: &TileMap) -> usize {
+    classify_tiles(map).count(Region::Inside)
+}
+
+/// Count the tiles enclosed by the main loop using the shoelace formula for the loop's area, then
+/// Pick's theorem (`A = i + b/2 - 1`) to derive the number of interior points from it. Only used
+/// to cross-check [`classify_tiles`].
+#[cfg(test)]
+fn get_tiles_in_loop_pick(map: &TileMap) -> usize {
+    let path_loop = get_main_loop(map);
+
+    let double_area: i64 = path_loop
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| a.x as i64 * b.y as i64 - b.x as i64 * a.y as i64)
+        .sum();
+
+    let area = double_area.unsigned_abs() as usize / 2;
+
+    area + 1 - path_loop.len() / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use aoc_common::parse_test_input;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 10);
+
+    aoc_common::puzzle_fixture!("day10.txt");
+
+    #[rstest]
+    fn test_parse_map(test_input: Vec<String>) {
+        let map = parse_map(&test_input).unwrap();
+
+        assert_eq!(map.height, 5);
+        assert_eq!(map.width, 5);
+        assert_eq!(map.start, Position::new(2, 0));
+
+        let expected_tiles = vec![
+            vec![
+                Tile::Ground,
+                Tile::Ground,
+                Tile::PipeSE,
+                Tile::PipeSW,
+                Tile::Ground,
+            ],
+            vec![
+                Tile::Ground,
+                Tile::PipeSE,
+                Tile::PipeNW,
+                Tile::PipeNS,
+                Tile::Ground,
+            ],
+            vec![
+                Tile::PipeSE,
+                Tile::PipeNW,
+                Tile::Ground,
+                Tile::PipeNE,
+                Tile::PipeSW,
+            ],
+            vec![
+                Tile::PipeNS,
+                Tile::PipeSE,
+                Tile::PipeEW,
+                Tile::PipeEW,
+                Tile::PipeNW,
+            ],
+            vec![
+                Tile::PipeNE,
+                Tile::PipeNW,
+                Tile::Ground,
+                Tile::Ground,
+                Tile::Ground,
+            ],
+        ];
+
+        assert_eq!(map.tiles, expected_tiles);
+    }
+
+    #[rstest]
+    #[case(2, "SJ.X7", "invalid tile 'X'")]
+    #[case(3, "|F--J.", "expected 5 columns, got 6")]
+    fn test_parse_map_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_map(&input),
+            Err(ParseError::new("day10", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    #[case(Position::new(2, 0), [Some(Position::new(3, 0)), Some(Position::new(2, 1))])]
+    #[case(Position::new(0, 2), [Some(Position::new(1, 2)), Some(Position::new(0, 3))])]
+    #[case(Position::new(3, 2), [Some(Position::new(3, 1)), Some(Position::new(3, 3))])]
+    #[case(Position::new(2, 2), [None, None])]
+    fn test_get_successors(
+        test_input: Vec<String>,
+        #[case] pos: Position,
+        #[case] expected: Successors,
+    ) {
+        let map = parse_map(&test_input).unwrap();
+
+        assert_eq!(map.get_successors(&pos), expected);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let map = parse_map(&test_input).unwrap();
+
+        let res = get_farthest_from_start(&map);
+
+        assert_eq!(res, 8);
+    }
+
+    #[rstest]
+    fn test_p2() {
+        let test_input = parse_test_input(
+            "
+            FF7FSF7F7F7F7F7F---7
+            L|LJ||||||||||||F--J
+            FL-7LJLJ||||||LJL-77
+            F--JF--7||LJLJ.F7FJ-
+            L---JF-JLJ....FJLJJ7
+            |F|F-JF---7...L7L|7|
+            |FFJF7L7F-JF7..L---7
+            7-L-JL7||F7|L7F-7F7|
+            L.L7LFJ|||||FJL7||LJ
+            L7JLJL-JLJLJL--JLJ.L
+        ",
+        );
+        let map = parse_map(&test_input).unwrap();
+
+        assert_eq!(get_tiles_in_loop(&map), 10);
+        aoc_common::assert_variants_agree(&[
+            ("parity scan", &|| get_tiles_in_loop(&map)),
+            ("pick", &|| get_tiles_in_loop_pick(&map)),
+        ]);
+        insta::assert_snapshot!(classify_tiles(&map).to_string());
+    }
+
+    #[rstest]
+    fn test_classify_tiles(test_input: Vec<String>) {
+        let map = parse_map(&test_input).unwrap();
+
+        insta::assert_snapshot!(classify_tiles(&map).to_string());
+    }
+
+    #[rstest]
+    fn test_p2_squeezing_between_pipes() {
+        let test_input = parse_test_input(
+            "
+            ..........
+            .S------7.
+            .|F----7|.
+            .||....||.
+            .||....||.
+            .|L-7F-J|.
+            .|..||..|.
+            .L--JL--J.
+            ..........
+        ",
+        );
+        let map = parse_map(&test_input).unwrap();
+
+        assert_eq!(get_tiles_in_loop(&map), 4);
+        aoc_common::assert_variants_agree(&[
+            ("parity scan", &|| get_tiles_in_loop(&map)),
+            ("pick", &|| get_tiles_in_loop_pick(&map)),
+        ]);
+        insta::assert_snapshot!(classify_tiles(&map).to_string());
+    }
+
+    #[rstest]
+    fn test_render_svg() {
+        let test_input = parse_test_input(
+            "
+            .....
+            .S-7.
+            .|.|.
+            .L-J.
+            .....
+        ",
+        );
+        let map = parse_map(&test_input).unwrap();
+
+        insta::assert_snapshot!(render_svg(&map));
+    }
+
+    #[rstest]
+    fn test_p2_variants_agree_full_input() {
+        aoc_common::require_input!("day10.txt");
+        let puzzle_input = puzzle_input();
+
+        let map = parse_map(&puzzle_input).unwrap();
+
+        aoc_common::assert_variants_agree(&[
+            ("parity scan", &|| get_tiles_in_loop(&map)),
+            ("pick", &|| get_tiles_in_loop_pick(&map)),
+        ]);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let map = parse_map(&input).unwrap();
+        get_farthest_from_start(&map)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let map = parse_map(&input).unwrap();
+        get_tiles_in_loop(&map)
+    }
+
+    aoc_common::answer_tests!("day10", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day10", part1, part2);
+
+    aoc_common::perf_test!("day10", 10, puzzle_input(), solve);
+}
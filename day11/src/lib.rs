@@ -0,0 +1,550 @@
+use std::time::Instant;
+use std::{collections::HashSet, fmt::Display};
+
+use aoc_common::dump::dump_parsed_flag;
+use aoc_common::{format_duration, InputSource, ParseError, Point};
+use serde::Serialize;
+
+/// Solve the puzzle input and print the answers, as the `day11` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day11.txt").read();
+
+    if let Some(format) = dump_parsed_flag() {
+        let space_map = parse(&input).unwrap_or_else(|e| panic!("{}", e));
+        print!("{}", format.dump(&space_map));
+        return;
+    }
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let debug = args.iter().any(|a| a == "--debug");
+    let pair = args
+        .iter()
+        .find_map(|a| a.strip_prefix("--pair=").map(parse_pair));
+
+    if !debug && pair.is_none() {
+        return;
+    }
+
+    let space_map = parse(&input).unwrap_or_else(|e| panic!("{}", e));
+
+    if debug {
+        println!();
+        print!("{}", render_space_map(&space_map));
+    }
+
+    if let Some((a, b)) = pair {
+        assert!(
+            a.max(b) < space_map.galaxies.len(),
+            "Invalid pair, there are only {} galaxies",
+            space_map.galaxies.len()
+        );
+
+        println!();
+        print!("{}", describe_distance(&space_map, a, b));
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let space_map = parse(input)?;
+
+    let p1 = get_sum_of_minimum_distances(&space_map, 2);
+    let p2 = get_sum_of_minimum_distances(&space_map, 1_000_000);
+
+    Ok((p1, p2))
+}
+
+type Position = Point<usize>;
+
+/// The image of the galaxies, before expansion. The galaxies are given by their position, with
+/// `x` as the row and `y` as the column, and referred to by their index in `galaxies`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SpaceMap {
+    pub height: usize,
+    pub width: usize,
+    pub galaxies: Vec<Position>,
+    pub empty_rows: Vec<usize>,
+    pub empty_columns: Vec<usize>,
+}
+
+/// The distance between two galaxies, given by their index.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct GalaxyDistance {
+    pub a: usize,
+    pub b: usize,
+    pub distance: usize,
+}
+
+impl SpaceMap {
+    /// Get the position of a galaxy once the empty rows and columns have been expanded.
+    pub fn get_expanded_position(&self, idx: usize, expansion_factor: usize) -> Position {
+        let g = self.galaxies[idx];
+
+        let empty_rows_before = self.empty_rows.partition_point(|&r| r < g.x);
+        let empty_columns_before = self.empty_columns.partition_point(|&c| c < g.y);
+
+        Position::new(
+            g.x + empty_rows_before * (expansion_factor - 1),
+            g.y + empty_columns_before * (expansion_factor - 1),
+        )
+    }
+
+    /// Get the distance between two galaxies once the empty rows and columns have been expanded.
+    pub fn get_distance(&self, idx_a: usize, idx_b: usize, expansion_factor: usize) -> usize {
+        let ga = self.galaxies[idx_a];
+        let gb = self.galaxies[idx_b];
+
+        let dx = ga.x.abs_diff(gb.x);
+        let dy = ga.y.abs_diff(gb.y);
+        let (empty_rows, empty_columns) = self.get_empty_lines_between(idx_a, idx_b);
+
+        dx + dy + (empty_rows + empty_columns) * (expansion_factor - 1)
+    }
+
+    /// Get the number of empty rows and columns between two galaxies, which are the ones that
+    /// get expanded in the distance between them.
+    pub fn get_empty_lines_between(&self, idx_a: usize, idx_b: usize) -> (usize, usize) {
+        let ga = self.galaxies[idx_a];
+        let gb = self.galaxies[idx_b];
+
+        let x1 = ga.x.min(gb.x);
+        let x2 = ga.x.max(gb.x);
+        let y1 = ga.y.min(gb.y);
+        let y2 = ga.y.max(gb.y);
+
+        let rows = self
+            .empty_rows
+            .iter()
+            .filter(|&&r| r > x1 && r < x2)
+            .count();
+        let columns = self
+            .empty_columns
+            .iter()
+            .filter(|&&c| c > y1 && c < y2)
+            .count();
+
+        (rows, columns)
+    }
+
+    /// Get the distance between every pair of galaxies once the empty rows and columns have been
+    /// expanded, ordered by the index of the first galaxy, then of the second one.
+    pub fn distances(&self, expansion_factor: usize) -> Vec<GalaxyDistance> {
+        (0..self.galaxies.len())
+            .flat_map(|a| (a + 1..self.galaxies.len()).map(move |b| (a, b)))
+            .map(|(a, b)| GalaxyDistance {
+                a,
+                b,
+                distance: self.get_distance(a, b, expansion_factor),
+            })
+            .collect()
+    }
+}
+
+/// Parse the image of the galaxies, e.g. to explore the distances of an input from a notebook.
+pub fn parse(input: &[String]) -> Result<SpaceMap, ParseError> {
+    let height = input.len();
+    let width = input.first().map_or(0, |l| l.len());
+
+    let mut galaxies = Vec::new();
+
+    for (x, row) in input.iter().enumerate() {
+        if row.len() != width {
+            let reason = format!("expected {} columns, got {}", width, row.len());
+            return Err(ParseError::new("day11", x + 1, row, reason));
+        }
+
+        for (y, i) in row.chars().enumerate() {
+            match i {
+                '#' => galaxies.push(Position::new(x, y)),
+                '.' => {}
+                _ => {
+                    let reason = format!("invalid character {:?}", i);
+                    return Err(ParseError::new("day11", x + 1, row, reason));
+                }
+            }
+        }
+    }
+
+    let occupied_rows = galaxies.iter().map(|g| g.x).collect::<HashSet<usize>>();
+    let occupied_columns = galaxies.iter().map(|g| g.y).collect::<HashSet<usize>>();
+
+    let empty_rows = (0..height).filter(|i| !occupied_rows.contains(i)).collect();
+    let empty_columns = (0..width)
+        .filter(|i| !occupied_columns.contains(i))
+        .collect();
+
+    Ok(SpaceMap {
+        height,
+        width,
+        galaxies,
+        empty_rows,
+        empty_columns,
+    })
+}
+
+/// Sum the distances between every pair of galaxies. Since the Manhattan distance decomposes
+/// per axis, each axis is handled separately: once its coordinates are sorted, the coordinate at
+/// index `i` contributes positively to its `i` pairs with the smaller coordinates.
+pub fn get_sum_of_minimum_distances(space_map: &SpaceMap, expansion_factor: usize) -> usize {
+    let (mut xs, mut ys): (Vec<usize>, Vec<usize>) = (0..space_map.galaxies.len())
+        .map(|i| space_map.get_expanded_position(i, expansion_factor))
+        .map(|p| (p.x, p.y))
+        .unzip();
+
+    xs.sort_unstable();
+    ys.sort_unstable();
+
+    get_sum_of_axis_distances(&xs) + get_sum_of_axis_distances(&ys)
+}
+
+fn get_sum_of_axis_distances(sorted_coords: &[usize]) -> usize {
+    let mut sum = 0;
+    let mut prefix = 0;
+
+    for (i, &c) in sorted_coords.iter().enumerate() {
+        sum += c * i - prefix;
+        prefix += c;
+    }
+
+    sum
+}
+
+/// Draw the map before expansion, with the galaxies labeled by their index and the empty rows
+/// and columns marked with `>` and `v` and drawn with `-` and `|` (`+` where they cross).
+fn render_space_map(space_map: &SpaceMap) -> String {
+    let width = space_map.galaxies.len().saturating_sub(1).to_string().len();
+    let mut cells = vec![vec![String::new(); space_map.width]; space_map.height];
+
+    for (x, row) in cells.iter_mut().enumerate() {
+        let empty_row = space_map.empty_rows.contains(&x);
+
+        for (y, cell) in row.iter_mut().enumerate() {
+            let c = match (empty_row, space_map.empty_columns.contains(&y)) {
+                (true, true) => "+",
+                (true, false) => "-",
+                (false, true) => "|",
+                (false, false) => ".",
+            };
+            *cell = c.to_string();
+        }
+    }
+
+    for (idx, g) in space_map.galaxies.iter().enumerate() {
+        cells[g.x][g.y] = idx.to_string();
+    }
+
+    let header = (0..space_map.width)
+        .map(|y| {
+            let c = if space_map.empty_columns.contains(&y) {
+                "v"
+            } else {
+                ""
+            };
+            format!("{:>width$}", c)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    let mut output = format!("  {}\n", header.trim_end());
+
+    for (x, row) in cells.iter().enumerate() {
+        let marker = if space_map.empty_rows.contains(&x) {
+            '>'
+        } else {
+            ' '
+        };
+        let row = row
+            .iter()
+            .map(|c| format!("{:>width$}", c))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        output.push_str(&format!("{} {}\n", marker, row));
+    }
+
+    output
+}
+
+/// Explain the distance between two galaxies: how far apart they are before expansion, how many
+/// empty rows and columns are between them, and the resulting distance for both parts.
+fn describe_distance(space_map: &SpaceMap, idx_a: usize, idx_b: usize) -> String {
+    let ga = space_map.galaxies[idx_a];
+    let gb = space_map.galaxies[idx_b];
+    let (empty_rows, empty_columns) = space_map.get_empty_lines_between(idx_a, idx_b);
+
+    let mut output = format!(
+        "Galaxies {} {:?} and {} {:?}: {} rows and {} columns apart, with {} empty rows and {} \
+         empty columns in between\n",
+        idx_a,
+        (ga.x, ga.y),
+        idx_b,
+        (gb.x, gb.y),
+        ga.x.abs_diff(gb.x),
+        ga.y.abs_diff(gb.y),
+        empty_rows,
+        empty_columns,
+    );
+
+    for expansion_factor in [2, 1_000_000] {
+        output.push_str(&format!(
+            "  Expansion factor {}: {} + {} * ({} - 1) = {}\n",
+            expansion_factor,
+            ga.x.abs_diff(gb.x) + ga.y.abs_diff(gb.y),
+            empty_rows + empty_columns,
+            expansion_factor,
+            space_map.get_distance(idx_a, idx_b, expansion_factor)
+        ));
+    }
+
+    output
+}
+
+fn parse_pair(value: &str) -> (usize, usize) {
+    let Some((a, b)) = value.split_once(',') else {
+        panic!("Invalid pair, expected GALAXY,GALAXY: {}", value);
+    };
+
+    (
+        a.parse().expect("Invalid first galaxy"),
+        b.parse().expect("Invalid second galaxy"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::dump::DumpFormat;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 11);
+
+    aoc_common::puzzle_fixture!("day11.txt");
+
+    #[rstest]
+    fn test_parse_space_map(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        let expected_map = SpaceMap {
+            height: 10,
+            width: 10,
+            galaxies: vec![
+                Position::new(0, 3),
+                Position::new(1, 7),
+                Position::new(2, 0),
+                Position::new(4, 6),
+                Position::new(5, 1),
+                Position::new(6, 9),
+                Position::new(8, 7),
+                Position::new(9, 0),
+                Position::new(9, 4),
+            ],
+            empty_rows: vec![3, 7],
+            empty_columns: vec![2, 5, 8],
+        };
+
+        assert_eq!(space_map, expected_map);
+    }
+
+    #[rstest]
+    #[case(1, ".....#...", "expected 10 columns, got 9")]
+    #[case(4, ".......O..", "invalid character 'O'")]
+    fn test_parse_space_map_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse(&input),
+            Err(ParseError::new("day11", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_dump_space_map(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+        let dump = DumpFormat::Json.dump(&space_map);
+
+        assert!(dump.starts_with("{\n  \"height\": 10,\n  \"width\": 10,\n  \"galaxies\": [\n"));
+        assert!(dump.contains("\"empty_rows\": [\n    3,\n    7\n  ],"));
+    }
+
+    #[rstest]
+    #[case(4, 8, 9)]
+    #[case(0, 6, 15)]
+    #[case(2, 5, 17)]
+    #[case(7, 8, 5)]
+    fn test_get_distance(
+        test_input: Vec<String>,
+        #[case] x: usize,
+        #[case] y: usize,
+        #[case] expected: usize,
+    ) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(space_map.get_distance(x, y, 2), expected);
+    }
+
+    #[rstest]
+    #[case(0, 2, Position::new(0, 4))]
+    #[case(8, 2, Position::new(11, 5))]
+    #[case(8, 10, Position::new(27, 13))]
+    fn test_get_expanded_position(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] expansion_factor: usize,
+        #[case] expected: Position,
+    ) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(
+            space_map.get_expanded_position(idx, expansion_factor),
+            expected
+        );
+    }
+
+    #[rstest]
+    fn test_get_distance_matches_expanded_positions(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        for a in 0..space_map.galaxies.len() {
+            for b in 0..space_map.galaxies.len() {
+                let pa = space_map.get_expanded_position(a, 10);
+                let pb = space_map.get_expanded_position(b, 10);
+
+                assert_eq!(
+                    space_map.get_distance(a, b, 10),
+                    pa.x.abs_diff(pb.x) + pa.y.abs_diff(pb.y)
+                );
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_distances(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+        let distances = space_map.distances(2);
+
+        assert_eq!(distances.len(), 36);
+        assert_eq!(
+            distances[..2],
+            [
+                GalaxyDistance {
+                    a: 0,
+                    b: 1,
+                    distance: 6
+                },
+                GalaxyDistance {
+                    a: 0,
+                    b: 2,
+                    distance: 6
+                },
+            ]
+        );
+        assert!(distances.contains(&GalaxyDistance {
+            a: 4,
+            b: 8,
+            distance: 9
+        }));
+        assert_eq!(distances.iter().map(|d| d.distance).sum::<usize>(), 374);
+    }
+
+    #[rstest]
+    fn test_render_space_map(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(
+            render_space_map(&space_map),
+            [
+                "      v     v     v",
+                "  . . | 0 . | . . | .",
+                "  . . | . . | . 1 | .",
+                "  2 . | . . | . . | .",
+                "> - - + - - + - - + -",
+                "  . . | . . | 3 . | .",
+                "  . 4 | . . | . . | .",
+                "  . . | . . | . . | 5",
+                "> - - + - - + - - + -",
+                "  . . | . . | . 6 | .",
+                "  7 . | . 8 | . . | .",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[rstest]
+    fn test_describe_distance(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(
+            describe_distance(&space_map, 4, 8),
+            [
+                "Galaxies 4 (5, 1) and 8 (9, 4): 4 rows and 3 columns apart, with 1 empty rows and \
+                 1 empty columns in between",
+                "  Expansion factor 2: 7 + 2 * (2 - 1) = 9",
+                "  Expansion factor 1000000: 7 + 2 * (1000000 - 1) = 2000005",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[rstest]
+    #[case("4,8", (4, 8))]
+    #[case("0,12", (0, 12))]
+    fn test_parse_pair(#[case] value: &str, #[case] expected: (usize, usize)) {
+        assert_eq!(parse_pair(value), expected);
+    }
+
+    #[rstest]
+    #[should_panic(expected = "Invalid pair")]
+    fn test_parse_pair_invalid() {
+        parse_pair("4");
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(get_sum_of_minimum_distances(&space_map, 2), 374);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let space_map = parse(&test_input).unwrap();
+
+        assert_eq!(get_sum_of_minimum_distances(&space_map, 10), 1030);
+        assert_eq!(get_sum_of_minimum_distances(&space_map, 100), 8410);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let space_map = parse(&input).unwrap();
+        get_sum_of_minimum_distances(&space_map, 2)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let space_map = parse(&input).unwrap();
+        get_sum_of_minimum_distances(&space_map, 1_000_000)
+    }
+
+    aoc_common::answer_tests!("day11", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day11", part1, part2);
+
+    aoc_common::perf_test!("day11", 1, puzzle_input(), solve);
+}
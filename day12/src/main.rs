@@ -1,10 +1,10 @@
 use std::fmt::Display;
 use std::time::Instant;
 
-use aoc_common::{format_duration, get_input};
+use aoc_common::{format_duration, InputSource};
 
 fn main() {
-    let input = get_input("day12.txt");
+    let input = InputSource::from_args("day12.txt").read();
 
     let start = Instant::now();
 
@@ -23,50 +23,3 @@ fn solve(_input: &[String]) -> (impl Display, impl Display) {
 
     (p1, p2)
 }
-
-#[cfg(test)]
-mod tests {
-    use rstest::{fixture, rstest};
-
-    use aoc_common::parse_test_input;
-
-    use super::*;
-
-    #[fixture]
-    fn test_input() -> Vec<String> {
-        parse_test_input("")
-    }
-
-    #[fixture]
-    fn puzzle_input() -> Vec<String> {
-        get_input("day12.txt")
-    }
-
-    #[rstest]
-    fn test_p1(test_input: Vec<String>) {
-        let res = 0;
-
-        assert_eq!(res, 1);
-    }
-
-    #[rstest]
-    fn test_p1_full_input(puzzle_input: Vec<String>) {
-        let res = 0;
-
-        assert_eq!(res, 1);
-    }
-
-    #[rstest]
-    fn test_p2(test_input: Vec<String>) {
-        let res = 0;
-
-        assert_eq!(res, 1);
-    }
-
-    #[rstest]
-    fn test_p2_full_input(puzzle_input: Vec<String>) {
-        let res = 0;
-
-        assert_eq!(res, 1);
-    }
-}
@@ -0,0 +1,615 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day13` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day13.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if std::env::args().any(|a| a == "--debug") {
+        let patterns = parse_patterns(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        for (idx, pattern) in patterns.iter().enumerate() {
+            for (label, mirror) in [
+                ("without smudge", find_mirror(pattern)),
+                ("with smudge", find_mirror_with_smudge(pattern)),
+            ] {
+                println!();
+                println!("Pattern {}, {}: {:?}", idx + 1, label, mirror);
+                print!("{}", render_mirror(pattern, &mirror));
+            }
+        }
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let patterns = parse_patterns(input)?;
+    let mirrors = find_mirrors(&patterns);
+    let mirrors_with_smudge = find_mirrors_with_smudge(&patterns);
+
+    let p1 = get_summary_value(&mirrors);
+    let p2 = get_summary_value(&mirrors_with_smudge);
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, PartialEq)]
+enum Mirror {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Mirror {
+    fn value(&self) -> usize {
+        match *self {
+            Self::Vertical(i) => i,
+            Self::Horizontal(i) => i * 100,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Pattern {
+    height: usize,
+    width: usize,
+    rows: Vec<u128>,
+    cols: Vec<u128>,
+}
+
+/// Patterns are stored as bitmasks of their rows and columns, so they can't be larger than this.
+const MAX_PATTERN_SIZE: usize = 128;
+
+fn parse_patterns(input: &[String]) -> Result<Vec<Pattern>, ParseError> {
+    let mut width = None;
+    let mut height = 0;
+
+    for (idx, line) in input.iter().enumerate() {
+        if line.is_empty() {
+            width = None;
+            height = 0;
+            continue;
+        }
+
+        let expected_width = *width.get_or_insert(line.len());
+        if line.len() != expected_width {
+            let reason = format!("expected {} columns, got {}", expected_width, line.len());
+            return Err(ParseError::new("day13", idx + 1, line, reason));
+        }
+
+        height += 1;
+        if line.len() > MAX_PATTERN_SIZE || height > MAX_PATTERN_SIZE {
+            let reason = format!(
+                "patterns are limited to {}x{}",
+                MAX_PATTERN_SIZE, MAX_PATTERN_SIZE
+            );
+            return Err(ParseError::new("day13", idx + 1, line, reason));
+        }
+
+        if let Some(c) = line.chars().find(|c| !matches!(c, '.' | '#')) {
+            let reason = format!("invalid character {:?}", c);
+            return Err(ParseError::new("day13", idx + 1, line, reason));
+        }
+    }
+
+    Ok(input.split(|i| i.is_empty()).map(parse_pattern).collect())
+}
+
+fn parse_pattern(input: &[String]) -> Pattern {
+    let height = input.len();
+    let width = input[0].len();
+
+    let mut rows = vec![0; height];
+    let mut cols = vec![0; width];
+
+    for (x, row) in input.iter().enumerate() {
+        for (y, item) in row.chars().enumerate() {
+            if item != '#' {
+                continue;
+            }
+
+            rows[x] |= 1 << (width - y - 1);
+            cols[y] |= 1 << (height - x - 1);
+        }
+    }
+
+    Pattern {
+        height,
+        width,
+        rows,
+        cols,
+    }
+}
+
+fn find_mirrors(patterns: &[Pattern]) -> Vec<Mirror> {
+    patterns.iter().map(find_mirror).collect()
+}
+
+fn find_mirrors_with_smudge(patterns: &[Pattern]) -> Vec<Mirror> {
+    patterns.iter().map(find_mirror_with_smudge).collect()
+}
+
+fn is_mirrored(values: &[u128]) -> bool {
+    let count = values.len();
+    if !count.is_multiple_of(2) {
+        return false;
+    }
+
+    (0..count / 2).all(|i| values[i] == values[count - i - 1])
+}
+
+fn find_mirror(pattern: &Pattern) -> Mirror {
+    let nrows = pattern.rows.len();
+
+    for i in 0..nrows - 1 {
+        if is_mirrored(&pattern.rows[i..]) {
+            return Mirror::Horizontal((nrows + i) / 2);
+        }
+        if is_mirrored(&pattern.rows[..nrows - i]) {
+            return Mirror::Horizontal((nrows + i) / 2 - i);
+        }
+    }
+
+    let ncols = pattern.cols.len();
+
+    for i in 0..ncols - 1 {
+        if is_mirrored(&pattern.cols[i..]) {
+            return Mirror::Vertical((ncols + i) / 2);
+        }
+
+        if is_mirrored(&pattern.cols[..ncols - i]) {
+            return Mirror::Vertical((ncols + i) / 2 - i);
+        }
+    }
+
+    panic!("No mirror found")
+}
+
+fn is_mirrored_with_one_smudge(values: &[u128]) -> bool {
+    let count = values.len();
+    if !count.is_multiple_of(2) {
+        return false;
+    }
+    let mut total = 0;
+
+    for i in 0..count / 2 {
+        total += (values[i] ^ values[count - i - 1]).count_ones();
+
+        if total > 1 {
+            return false;
+        }
+    }
+
+    total == 1
+}
+
+fn find_mirror_with_smudge(pattern: &Pattern) -> Mirror {
+    let nrows = pattern.rows.len();
+
+    for i in 0..nrows - 1 {
+        if is_mirrored_with_one_smudge(&pattern.rows[i..]) {
+            return Mirror::Horizontal((nrows + i) / 2);
+        }
+        if is_mirrored_with_one_smudge(&pattern.rows[..nrows - i]) {
+            return Mirror::Horizontal((nrows + i) / 2 - i);
+        }
+    }
+
+    let ncols = pattern.cols.len();
+
+    for i in 0..ncols - 1 {
+        if is_mirrored_with_one_smudge(&pattern.cols[i..]) {
+            return Mirror::Vertical((ncols + i) / 2);
+        }
+        if is_mirrored_with_one_smudge(&pattern.cols[..ncols - i]) {
+            return Mirror::Vertical((ncols + i) / 2 - i);
+        }
+    }
+
+    panic!("No mirror found")
+}
+
+fn get_summary_value(mirrors: &[Mirror]) -> usize {
+    mirrors.iter().map(|m| m.value()).sum()
+}
+
+/// Draw a pattern with its reflection line between the mirrored rows or columns, `-` for a
+/// horizontal mirror and `|` for a vertical one.
+fn render_mirror(pattern: &Pattern, mirror: &Mirror) -> String {
+    let mut output = String::new();
+
+    for (x, &row) in pattern.rows.iter().enumerate() {
+        if *mirror == Mirror::Horizontal(x) {
+            output.push_str(&"-".repeat(pattern.width));
+            output.push('\n');
+        }
+
+        for y in 0..pattern.width {
+            if *mirror == Mirror::Vertical(y) {
+                output.push('|');
+            }
+
+            let is_rock = row & (1 << (pattern.width - y - 1)) != 0;
+            output.push(if is_rock { '#' } else { '.' });
+        }
+
+        output.push('\n');
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_test_input;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 13);
+
+    aoc_common::puzzle_fixture!("day13.txt");
+
+    /// Build a pseudo-random pattern whose columns are mirrored after column `axis`.
+    fn mirrored_pattern(height: usize, width: usize, axis: usize) -> Vec<String> {
+        let mut state: u64 = 0x2545F4914F6CDD1D;
+
+        (0..height)
+            .map(|_| {
+                let left: Vec<char> = (0..axis)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        if state >> 63 == 1 {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect();
+
+                (0..width)
+                    .map(|y| {
+                        if y < axis {
+                            left[y]
+                        } else {
+                            left[2 * axis - 1 - y]
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn transpose(input: &[String]) -> Vec<String> {
+        let rows: Vec<Vec<char>> = input.iter().map(|r| r.chars().collect()).collect();
+
+        (0..rows[0].len())
+            .map(|y| rows.iter().map(|r| r[y]).collect())
+            .collect()
+    }
+
+    fn toggle(input: &mut [String], x: usize, y: usize) {
+        let mut row: Vec<char> = input[x].chars().collect();
+        row[y] = if row[y] == '#' { '.' } else { '#' };
+        input[x] = row.into_iter().collect();
+    }
+
+    #[rstest]
+    fn test_parse_patterns(test_input: Vec<String>) {
+        let patterns = parse_patterns(&test_input).unwrap();
+
+        assert_eq!(
+            patterns,
+            vec![
+                Pattern {
+                    height: 7,
+                    width: 9,
+                    rows: vec![
+                        0b101100110,
+                        0b1011010,
+                        0b110000001,
+                        0b110000001,
+                        0b1011010,
+                        0b1100110,
+                        0b0101011010
+                    ],
+                    cols: vec![
+                        0b1011001, 0b11000, 0b1100111, 0b1000010, 0b100101, 0b100101, 0b1000010,
+                        0b1100111, 0b11000
+                    ],
+                },
+                Pattern {
+                    height: 7,
+                    width: 9,
+                    rows: vec![
+                        0b100011001,
+                        0b100001001,
+                        0b1100111,
+                        0b111110110,
+                        0b111110110,
+                        0b1100111,
+                        0b100001001
+                    ],
+                    cols: vec![
+                        0b1101101, 0b1100, 0b11110, 0b11110, 0b1001100, 0b1100001, 0b11110,
+                        0b11110, 0b1110011
+                    ],
+                },
+            ]
+        )
+    }
+
+    #[rstest]
+    #[case(3, "##......", "expected 9 columns, got 8")]
+    #[case(10, "#...##..x", "invalid character 'x'")]
+    fn test_parse_patterns_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_patterns(&input),
+            Err(ParseError::new("day13", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_pattern_wider_than_64() {
+        let row = format!("#{}#", ".".repeat(98));
+        let pattern = parse_pattern(&[row.clone(), row]);
+
+        assert_eq!(pattern.rows, vec![1 << 99 | 1, 1 << 99 | 1]);
+        assert_eq!(pattern.cols[0], 0b11);
+        assert_eq!(pattern.cols[99], 0b11);
+        assert!(pattern.cols[1..99].iter().all(|&c| c == 0));
+    }
+
+    #[rstest]
+    fn test_parse_pattern_wider_than_128() {
+        let line = "#".repeat(129);
+        let input = vec!["#.".to_string(), String::new(), line.clone()];
+
+        assert_eq!(
+            parse_patterns(&input),
+            Err(ParseError::new(
+                "day13",
+                3,
+                &line,
+                "patterns are limited to 128x128"
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_pattern_taller_than_128() {
+        let mut input = vec!["#".to_string(); 129];
+        input.extend(["".to_string(), "#".to_string()]);
+
+        assert_eq!(
+            parse_patterns(&input),
+            Err(ParseError::new(
+                "day13",
+                129,
+                "#",
+                "patterns are limited to 128x128"
+            ))
+        );
+
+        assert!(parse_patterns(&input[1..]).is_ok());
+    }
+
+    #[rstest]
+    #[case(5, 100, 70)]
+    #[case(5, 128, 100)]
+    #[case(7, 90, 50)]
+    fn test_find_mirror_wide_pattern(
+        #[case] height: usize,
+        #[case] width: usize,
+        #[case] axis: usize,
+    ) {
+        let input = mirrored_pattern(height, width, axis);
+
+        assert_eq!(find_mirror(&parse_pattern(&input)), Mirror::Vertical(axis));
+
+        let transposed = transpose(&input);
+        assert_eq!(
+            find_mirror(&parse_pattern(&transposed)),
+            Mirror::Horizontal(axis)
+        );
+    }
+
+    #[rstest]
+    #[case(5, 100, 70, 2, 99)]
+    #[case(5, 128, 100, 4, 72)]
+    fn test_find_mirror_with_smudge_wide_pattern(
+        #[case] height: usize,
+        #[case] width: usize,
+        #[case] axis: usize,
+        #[case] smudge_x: usize,
+        #[case] smudge_y: usize,
+    ) {
+        let mut input = mirrored_pattern(height, width, axis);
+        toggle(&mut input, smudge_x, smudge_y);
+
+        assert_eq!(
+            find_mirror_with_smudge(&parse_pattern(&input)),
+            Mirror::Vertical(axis)
+        );
+
+        let transposed = transpose(&input);
+        assert_eq!(
+            find_mirror_with_smudge(&parse_pattern(&transposed)),
+            Mirror::Horizontal(axis)
+        );
+    }
+
+    #[rstest]
+    fn test_find_mirrors(test_input: Vec<String>) {
+        let patterns = parse_patterns(&test_input).unwrap();
+
+        let mirrors = find_mirrors(&patterns);
+
+        assert_eq!(mirrors, vec![Mirror::Vertical(5), Mirror::Horizontal(4)]);
+    }
+
+    #[rstest]
+    #[case(0, false, Mirror::Vertical(5))]
+    #[case(1, false, Mirror::Horizontal(4))]
+    #[case(0, true, Mirror::Vertical(4))]
+    #[case(1, true, Mirror::Horizontal(3))]
+    fn test_find_mirror(
+        test_input: Vec<String>,
+        #[case] pattern_idx: usize,
+        #[case] reversed: bool,
+        #[case] expected_mirror: Mirror,
+    ) {
+        let mut pattern = parse_patterns(&test_input).unwrap()[pattern_idx].clone();
+
+        if reversed {
+            pattern = Pattern {
+                rows: pattern.rows.iter().rev().copied().collect(),
+                cols: pattern.cols.iter().rev().copied().collect(),
+                ..pattern
+            }
+        }
+
+        assert_eq!(find_mirror(&pattern), expected_mirror);
+    }
+
+    fn format_mirror(mirror: &Mirror) -> String {
+        match mirror {
+            Mirror::Vertical(i) => format!("V{}", i),
+            Mirror::Horizontal(i) => format!("H{}", i),
+        }
+    }
+
+    /// Check the mirrors found in every pattern of the puzzle input against
+    /// `input/day13.mirrors.txt`, which has one line per pattern with its mirror without and with
+    /// the smudge, e.g. `V8 H3`. Run with `UPDATE_EXPECTED=1` to regenerate the file from the
+    /// current implementation instead.
+    #[rstest]
+    fn test_find_mirrors_real_input() {
+        aoc_common::require_input!("day13.txt");
+        let puzzle_input = puzzle_input();
+
+        let patterns = parse_patterns(&puzzle_input).unwrap();
+        let actual: Vec<String> = find_mirrors(&patterns)
+            .iter()
+            .zip(find_mirrors_with_smudge(&patterns).iter())
+            .map(|(m, s)| format!("{} {}", format_mirror(m), format_mirror(s)))
+            .collect();
+
+        if std::env::var_os("UPDATE_EXPECTED").is_some() {
+            let path = format!("{}/../input/day13.mirrors.txt", env!("CARGO_MANIFEST_DIR"));
+            std::fs::write(path, actual.join("\n") + "\n").unwrap();
+            return;
+        }
+
+        let expected = aoc_common::get_input("day13.mirrors.txt");
+        assert_eq!(actual.len(), expected.len(), "Wrong number of patterns");
+
+        let mismatches: Vec<String> = actual
+            .iter()
+            .zip(expected.iter())
+            .enumerate()
+            .filter(|(_, (a, e))| a != e)
+            .map(|(idx, (a, e))| format!("pattern {}: expected {}, got {}", idx, e, a))
+            .collect();
+
+        assert!(mismatches.is_empty(), "{}", mismatches.join("\n"));
+    }
+
+    #[rstest]
+    fn test_render_mirror(test_input: Vec<String>) {
+        let patterns = parse_patterns(&test_input).unwrap();
+
+        assert_eq!(
+            render_mirror(&patterns[0], &Mirror::Vertical(5)),
+            parse_test_input(
+                "
+                #.##.|.##.
+                ..#.#|#.#.
+                ##...|...#
+                ##...|...#
+                ..#.#|#.#.
+                ..##.|.##.
+                #.#.#|#.#.
+                "
+            )
+            .join("\n")
+                + "\n"
+        );
+        assert_eq!(
+            render_mirror(&patterns[1], &Mirror::Horizontal(4)),
+            parse_test_input(
+                "
+                #...##..#
+                #....#..#
+                ..##..###
+                #####.##.
+                ---------
+                #####.##.
+                ..##..###
+                #....#..#
+                "
+            )
+            .join("\n")
+                + "\n"
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let patterns = parse_patterns(&test_input).unwrap();
+
+        let mirrors = find_mirrors(&patterns);
+
+        assert_eq!(get_summary_value(&mirrors), 405);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let patterns = parse_patterns(&test_input).unwrap();
+
+        let mirrors = find_mirrors_with_smudge(&patterns);
+
+        assert_eq!(get_summary_value(&mirrors), 400);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let patterns = parse_patterns(&input).unwrap();
+
+        let mirrors = find_mirrors(&patterns);
+        get_summary_value(&mirrors)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let patterns = parse_patterns(&input).unwrap();
+
+        let mirrors = find_mirrors_with_smudge(&patterns);
+        get_summary_value(&mirrors)
+    }
+
+    aoc_common::answer_tests!("day13", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day13", part1, part2);
+
+    aoc_common::perf_test!("day13", 2, puzzle_input(), solve);
+}
@@ -0,0 +1,805 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::viz::{Color, Frame, Visualizer};
+use aoc_common::{format_duration, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day14` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day14.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if let Some(mut visualizer) = Visualizer::from_args() {
+        let grid = parse_grid(&input).unwrap_or_else(|e| panic!("{}", e));
+        visualize_spin_cycles(grid, &mut visualizer).expect("Unable to draw the visualization");
+        visualizer
+            .finish()
+            .expect("Unable to finish the visualization");
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let mut grid = parse_grid(input)?;
+    grid.tilt(Direction::North);
+    let p1 = grid.get_load();
+
+    let mut grid = parse_grid(input)?;
+    grid.run_cycles(1_000_000_000);
+    let p2 = grid.get_load();
+
+    Ok((p1, p2))
+}
+
+#[derive(Debug, PartialEq, Clone)]
+enum Element {
+    Empty,
+    Square,
+    Round,
+}
+
+impl TryFrom<char> for Element {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Element::Empty),
+            '#' => Ok(Element::Square),
+            'O' => Ok(Element::Round),
+            _ => Err(format!("invalid character {:?}", value)),
+        }
+    }
+}
+
+impl Element {
+    fn to_char(&self) -> char {
+        match *self {
+            Element::Empty => '.',
+            Element::Square => '#',
+            Element::Round => 'O',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Direction {
+    North,
+    West,
+    South,
+    East,
+}
+
+/// A step of [`Grid::run_sequence_observed`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SequenceEvent {
+    /// The platform was tilted during the given cycle.
+    Tilted { cycle: usize, direction: Direction },
+    /// The state after `cycle` is the same as after `previous`.
+    Repeated { cycle: usize, previous: usize },
+}
+
+/// The tilt order of a spin cycle.
+const SPIN_CYCLE: [Direction; 4] = [
+    Direction::North,
+    Direction::West,
+    Direction::South,
+    Direction::East,
+];
+
+/// The platform, stored as one `u128` bitmask per row (bit `n` being column `n`) for the round
+/// rocks and for the cube rocks. Since cube rocks never move, their column-wise masks (bit `n`
+/// being row `n`) are also kept around for the north and south tilts.
+#[derive(PartialEq, Clone)]
+struct Grid {
+    height: usize,
+    width: usize,
+    round: Vec<u128>,
+    square: Vec<u128>,
+    square_cols: Vec<u128>,
+}
+
+impl std::fmt::Debug for Grid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("Grid {\n")?;
+
+        for r in self.elements() {
+            f.write_fmt(format_args!(
+                "{}\n",
+                r.iter().map(Element::to_char).collect::<String>()
+            ))?;
+        }
+
+        f.write_str("}\n")
+    }
+}
+
+impl Grid {
+    fn elements(&self) -> Vec<Vec<Element>> {
+        (0..self.height)
+            .map(|row| {
+                (0..self.width)
+                    .map(|col| {
+                        if self.round[row] & (1 << col) != 0 {
+                            Element::Round
+                        } else if self.square[row] & (1 << col) != 0 {
+                            Element::Square
+                        } else {
+                            Element::Empty
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Draw the platform, with the round rocks in `rocks_color`.
+    fn to_frame(&self, rocks_color: Color) -> Frame {
+        let mut frame = Frame::new(self.width, self.height, '.');
+
+        for (y, row) in self.elements().iter().enumerate() {
+            for (x, element) in row.iter().enumerate() {
+                match element {
+                    Element::Empty => {}
+                    Element::Square => frame.set(x, y, '#', Some(Color::Gray)),
+                    Element::Round => frame.set(x, y, 'O', Some(rocks_color)),
+                }
+            }
+        }
+
+        frame
+    }
+
+    fn tilt(&mut self, direction: Direction) {
+        match direction {
+            Direction::North | Direction::South => {
+                let cols = transpose(&self.round, self.width);
+                let cols = tilt_lines(
+                    &cols,
+                    &self.square_cols,
+                    self.height,
+                    direction == Direction::South,
+                );
+                self.round = transpose(&cols, self.height);
+            }
+            Direction::West | Direction::East => {
+                self.round = tilt_lines(
+                    &self.round,
+                    &self.square,
+                    self.width,
+                    direction == Direction::East,
+                );
+            }
+        }
+    }
+
+    fn run_cycles(&mut self, cycles: usize) {
+        self.run_sequence(&SPIN_CYCLE, cycles);
+    }
+
+    /// Apply the tilts of `sequence`, in order, `cycles` times. As soon as the rocks get back to
+    /// a previous state, the remaining cycles are skipped over.
+    fn run_sequence(&mut self, sequence: &[Direction], cycles: usize) {
+        self.run_sequence_observed(sequence, cycles, |_, _| {});
+    }
+
+    /// Same as [`Grid::run_sequence`], calling `observe` after each tilt of the cycles that are
+    /// actually run, and when a cycle gets back to the state of a previous one.
+    fn run_sequence_observed<F>(&mut self, sequence: &[Direction], cycles: usize, mut observe: F)
+    where
+        F: FnMut(&Grid, SequenceEvent),
+    {
+        let mut seen_states = HashMap::new();
+
+        let apply = |grid: &mut Grid, cycle: usize, observe: &mut F| {
+            for &direction in sequence {
+                grid.tilt(direction);
+                observe(grid, SequenceEvent::Tilted { cycle, direction });
+            }
+        };
+
+        for iteration in 1..=cycles {
+            apply(self, iteration, &mut observe);
+
+            if let Some(previous) = seen_states.insert(self.round.clone(), iteration) {
+                let event = SequenceEvent::Repeated {
+                    cycle: iteration,
+                    previous,
+                };
+                observe(self, event);
+
+                // Skip over whole periods, only running the cycles past the last one.
+                let remaining = (cycles - iteration) % (iteration - previous);
+
+                for cycle in cycles - remaining + 1..=cycles {
+                    apply(self, cycle, &mut observe);
+                }
+
+                return;
+            }
+        }
+    }
+
+    fn get_load(&self) -> usize {
+        self.round
+            .iter()
+            .enumerate()
+            .map(|(idx, row)| row.count_ones() as usize * (self.height - idx))
+            .sum()
+    }
+}
+
+/// Draw the platform after each tilt of the spin cycles, until the cycle detection kicks in. The
+/// repeated state is then drawn with its rocks highlighted.
+fn visualize_spin_cycles(mut grid: Grid, visualizer: &mut Visualizer) -> std::io::Result<()> {
+    let mut result = Ok(());
+
+    grid.run_sequence_observed(&SPIN_CYCLE, 1_000_000_000, |grid, event| {
+        if result.is_err() {
+            return;
+        }
+
+        result = match event {
+            SequenceEvent::Tilted { cycle, direction } => {
+                let caption = format!(
+                    "Cycle {}, tilted {:?}, load {}",
+                    cycle,
+                    direction,
+                    grid.get_load()
+                );
+                visualizer.draw(&grid.to_frame(Color::Yellow), Some(&caption))
+            }
+            SequenceEvent::Repeated { cycle, previous } => {
+                let caption = format!(
+                    "Cycle {} repeats cycle {}, every {} cycles",
+                    cycle,
+                    previous,
+                    cycle - previous
+                );
+                visualizer.draw(&grid.to_frame(Color::Magenta), Some(&caption))
+            }
+        };
+    });
+
+    result
+}
+
+/// Mask of the bits in `start..end`.
+#[inline]
+fn bit_range(start: usize, end: usize) -> u128 {
+    if start == end {
+        0
+    } else if end - start == 128 {
+        u128::MAX
+    } else {
+        ((1 << (end - start)) - 1) << start
+    }
+}
+
+/// Tilt every line of a platform. Within each segment delimited by cube rocks, the round rocks
+/// are counted and packed at the low end of the segment, or the high end if `towards_high` is
+/// set.
+fn tilt_lines(round: &[u128], square: &[u128], len: usize, towards_high: bool) -> Vec<u128> {
+    round
+        .iter()
+        .zip(square)
+        .map(|(&round, &square)| {
+            let mut tilted = 0;
+            let mut start = 0;
+
+            while start < len {
+                let end = match square >> start {
+                    0 => len,
+                    s => start + s.trailing_zeros() as usize,
+                };
+
+                let n = (round & bit_range(start, end)).count_ones() as usize;
+                if towards_high {
+                    tilted |= bit_range(end - n, end);
+                } else {
+                    tilted |= bit_range(start, start + n);
+                }
+
+                start = end + 1;
+            }
+
+            tilted
+        })
+        .collect()
+}
+
+/// Transpose a list of `len` bits wide lines into `len` lines.
+fn transpose(lines: &[u128], len: usize) -> Vec<u128> {
+    let mut transposed = vec![0; len];
+
+    for (i, &line) in lines.iter().enumerate() {
+        let mut bits = line;
+
+        while bits != 0 {
+            let j = bits.trailing_zeros() as usize;
+            transposed[j] |= 1 << i;
+            bits &= bits - 1;
+        }
+    }
+
+    transposed
+}
+
+fn parse_grid(input: &[String]) -> Result<Grid, ParseError> {
+    let height = input.len();
+    let width = input.first().map_or(0, |l| l.len());
+
+    // Rows and columns are stored as bitmasks in a u128.
+    if width > 128 {
+        return Err(ParseError::new(
+            "day14",
+            1,
+            &input[0],
+            "grid larger than 128x128",
+        ));
+    }
+    if height > 128 {
+        return Err(ParseError::new(
+            "day14",
+            129,
+            &input[128],
+            "grid larger than 128x128",
+        ));
+    }
+
+    let mut round = vec![0; height];
+    let mut square = vec![0; height];
+
+    for (row, line) in input.iter().enumerate() {
+        if line.len() != width {
+            let reason = format!("expected {} columns, got {}", width, line.len());
+            return Err(ParseError::new("day14", row + 1, line, reason));
+        }
+
+        for (col, c) in line.chars().enumerate() {
+            let element =
+                Element::try_from(c).map_err(|e| ParseError::new("day14", row + 1, line, e))?;
+
+            match element {
+                Element::Empty => {}
+                Element::Square => square[row] |= 1 << col,
+                Element::Round => round[row] |= 1 << col,
+            }
+        }
+    }
+
+    let square_cols = transpose(&square, width);
+
+    Ok(Grid {
+        height,
+        width,
+        round,
+        square,
+        square_cols,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 14);
+
+    aoc_common::puzzle_fixture!("day14.txt");
+
+    #[rstest]
+    fn test_parse_grid(test_input: Vec<String>) {
+        let grid = parse_grid(&test_input).unwrap();
+
+        assert_eq!(grid.height, 10);
+        assert_eq!(grid.width, 10);
+        assert_eq!(
+            grid.elements(),
+            vec![
+                vec![
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty
+                ],
+                vec![
+                    Element::Round,
+                    Element::Empty,
+                    Element::Round,
+                    Element::Round,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square
+                ],
+                vec![
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty
+                ],
+                vec![
+                    Element::Round,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round
+                ],
+                vec![
+                    Element::Empty,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round,
+                    Element::Square,
+                    Element::Empty
+                ],
+                vec![
+                    Element::Round,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Square
+                ],
+                vec![
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round
+                ],
+                vec![
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty
+                ],
+                vec![
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Square,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty
+                ],
+                vec![
+                    Element::Square,
+                    Element::Round,
+                    Element::Round,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Square,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty,
+                    Element::Empty
+                ]
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case(2, ".....##..", "expected 10 columns, got 9")]
+    #[case(5, "O.#..0.#.#", "invalid character '0'")]
+    fn test_parse_grid_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_grid(&input),
+            Err(ParseError::new("day14", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    #[case(10, 129, 1)]
+    #[case(129, 10, 129)]
+    fn test_parse_grid_too_large(
+        #[case] height: usize,
+        #[case] width: usize,
+        #[case] line_no: usize,
+    ) {
+        let input = vec![".".repeat(width); height];
+
+        assert_eq!(
+            parse_grid(&input),
+            Err(ParseError::new(
+                "day14",
+                line_no,
+                &".".repeat(width),
+                "grid larger than 128x128"
+            ))
+        );
+    }
+
+    #[rstest]
+    #[case(Direction::North)]
+    #[case(Direction::South)]
+    #[case(Direction::East)]
+    #[case(Direction::West)]
+    fn test_tilt(test_input: Vec<String>, #[case] direction: Direction) {
+        let mut grid = parse_grid(&test_input).unwrap();
+
+        grid.run_sequence(&[direction], 1);
+
+        insta::assert_debug_snapshot!(format!("tilt_{:?}", direction).to_lowercase(), grid);
+    }
+
+    fn rotate_180(input: &[String]) -> Vec<String> {
+        input
+            .iter()
+            .rev()
+            .map(|l| l.chars().rev().collect())
+            .collect()
+    }
+
+    fn transpose_lines(input: &[String]) -> Vec<String> {
+        let rows: Vec<Vec<char>> = input.iter().map(|r| r.chars().collect()).collect();
+
+        (0..rows[0].len())
+            .map(|col| rows.iter().map(|r| r[col]).collect())
+            .collect()
+    }
+
+    /// Build a pseudo-random platform, with about a quarter of round rocks and of cube rocks.
+    fn random_platform(height: usize, width: usize, seed: u64) -> Vec<String> {
+        let mut state = seed;
+
+        (0..height)
+            .map(|_| {
+                (0..width)
+                    .map(|_| {
+                        state = state
+                            .wrapping_mul(6364136223846793005)
+                            .wrapping_add(1442695040888963407);
+                        match state >> 62 {
+                            0 => 'O',
+                            1 => '#',
+                            _ => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn tilted(input: &[String], direction: Direction) -> Vec<String> {
+        let mut grid = parse_grid(input).unwrap();
+        grid.tilt(direction);
+
+        grid.elements()
+            .iter()
+            .map(|r| r.iter().map(Element::to_char).collect())
+            .collect()
+    }
+
+    /// Tilting a platform must be the same as transforming it, tilting it in the equivalent
+    /// direction and transforming it back, e.g. tilting south is rotating it by 180°, tilting
+    /// north and rotating it back.
+    #[rstest]
+    #[case(Direction::South, Direction::North, rotate_180)]
+    #[case(Direction::North, Direction::South, rotate_180)]
+    #[case(Direction::East, Direction::West, rotate_180)]
+    #[case(Direction::West, Direction::East, rotate_180)]
+    #[case(Direction::West, Direction::North, transpose_lines)]
+    #[case(Direction::East, Direction::South, transpose_lines)]
+    fn test_tilt_equivalence(
+        #[values(
+            aoc_common::get_example_input(14, None),
+            random_platform(1, 1, 1),
+            random_platform(1, 128, 2),
+            random_platform(128, 1, 3),
+            random_platform(7, 13, 4),
+            random_platform(128, 128, 5),
+            vec!["O".repeat(128); 128],
+            vec![".".repeat(127) + "O"; 3]
+        )]
+        platform: Vec<String>,
+        #[case] direction: Direction,
+        #[case] equivalent: Direction,
+        #[case] transform: fn(&[String]) -> Vec<String>,
+    ) {
+        let expected = transform(&tilted(&transform(&platform), equivalent));
+
+        assert_eq!(tilted(&platform, direction), expected);
+    }
+
+    #[rstest]
+    fn test_spin_cycle_snapshots(test_input: Vec<String>) {
+        let mut grid = parse_grid(&test_input).unwrap();
+
+        for cycle in 1..=3 {
+            for direction in SPIN_CYCLE {
+                grid.tilt(direction);
+
+                let name = format!("spin_cycle_{}_{:?}", cycle, direction).to_lowercase();
+                insta::assert_debug_snapshot!(name, grid);
+            }
+        }
+    }
+
+    #[rstest]
+    #[case(vec![Direction::North, Direction::East], 1000)]
+    #[case(vec![Direction::South, Direction::West, Direction::North], 7)]
+    #[case(SPIN_CYCLE.to_vec(), 25)]
+    fn test_run_sequence_matches_naive_tilting(
+        test_input: Vec<String>,
+        #[case] sequence: Vec<Direction>,
+        #[case] cycles: usize,
+    ) {
+        let mut grid = parse_grid(&test_input).unwrap();
+        grid.run_sequence(&sequence, cycles);
+
+        let mut expected = parse_grid(&test_input).unwrap();
+        for _ in 0..cycles {
+            for &direction in &sequence {
+                expected.tilt(direction);
+            }
+        }
+
+        assert_eq!(grid, expected);
+    }
+
+    #[rstest]
+    fn test_run_sequence_observed(test_input: Vec<String>) {
+        let mut grid = parse_grid(&test_input).unwrap();
+
+        let mut events = Vec::new();
+        grid.run_sequence_observed(&SPIN_CYCLE, 1_000_000_000, |_, e| events.push(e));
+
+        // The example repeats every 7 cycles from cycle 3, so only the last 3 cycles are run after
+        // detecting it.
+        let repeated = SequenceEvent::Repeated {
+            cycle: 10,
+            previous: 3,
+        };
+        assert_eq!(events.len(), 10 * 4 + 1 + 3 * 4);
+        assert_eq!(events[40], repeated);
+        assert_eq!(
+            events[..4],
+            SPIN_CYCLE.map(|direction| SequenceEvent::Tilted {
+                cycle: 1,
+                direction
+            })
+        );
+        assert_eq!(
+            events.last(),
+            Some(&SequenceEvent::Tilted {
+                cycle: 1_000_000_000,
+                direction: Direction::East
+            })
+        );
+        assert_eq!(grid.get_load(), 64);
+    }
+
+    #[rstest]
+    fn test_to_frame(test_input: Vec<String>) {
+        let grid = parse_grid(&test_input).unwrap();
+
+        let frame = grid.to_frame(Color::Magenta);
+
+        assert_eq!(frame.render_plain(), test_input.join("\n") + "\n");
+        assert_eq!(frame.get(0, 0).color, Some(Color::Magenta));
+        assert_eq!(frame.get(5, 0).color, Some(Color::Gray));
+        assert_eq!(frame.get(1, 0).color, None);
+    }
+
+    #[rstest]
+    fn test_get_load(test_input: Vec<String>) {
+        let grid = parse_grid(&test_input).unwrap();
+
+        assert_eq!(grid.get_load(), 104);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let mut grid = parse_grid(&test_input).unwrap();
+        grid.tilt(Direction::North);
+
+        assert_eq!(grid.get_load(), 136)
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let mut grid = parse_grid(&test_input).unwrap();
+        grid.run_cycles(1_000_000_000);
+
+        assert_eq!(grid.get_load(), 64)
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(2)]
+    #[case(3)]
+    #[case(10)]
+    #[case(25)]
+    fn test_run_cycles_matches_naive_cycling(test_input: Vec<String>, #[case] cycles: usize) {
+        let mut grid = parse_grid(&test_input).unwrap();
+        grid.run_cycles(cycles);
+
+        let mut expected = parse_grid(&test_input).unwrap();
+        for _ in 0..cycles {
+            for direction in SPIN_CYCLE {
+                expected.tilt(direction);
+            }
+        }
+
+        assert_eq!(grid, expected);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let mut grid = parse_grid(&input).unwrap();
+        grid.tilt(Direction::North);
+        grid.get_load()
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let mut grid = parse_grid(&input).unwrap();
+        grid.run_cycles(1_000_000_000);
+        grid.get_load()
+    }
+
+    aoc_common::answer_tests!("day14", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day14", part1, part2);
+
+    aoc_common::perf_test!("day14", 50, puzzle_input(), solve);
+}
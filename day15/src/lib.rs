@@ -0,0 +1,317 @@
+use std::fmt::Display;
+use std::time::Instant;
+
+use aoc_common::{format_duration, InputSource, ParseError};
+
+/// Solve the puzzle input and print the answers, as the `day15` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day15.txt").read_as_string();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(&input).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &str) -> Result<(impl Display, impl Display), ParseError> {
+    let instrs = parse_instructions(input)?;
+
+    let p1 = get_sum_of_hashes(&instrs);
+    let p2 = get_focusing_power(&instrs);
+
+    Ok((p1, p2))
+}
+
+struct HolidayHasher {
+    value: u16,
+}
+
+impl HolidayHasher {
+    fn new() -> Self {
+        Self { value: 0 }
+    }
+
+    fn write(&mut self, data: &str) {
+        for c in data.chars() {
+            self.value = ((self.value + c as u16) * 17) % 256
+        }
+    }
+
+    fn finish(&self) -> u8 {
+        self.value as u8
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Op {
+    Set(u8),
+    Remove,
+}
+
+#[derive(Debug, PartialEq)]
+struct Instruction<'a> {
+    raw: &'a str,
+    label: &'a str,
+    op: Op,
+}
+
+impl Instruction<'_> {
+    fn get_box_id(&self) -> usize {
+        let mut hasher = HolidayHasher::new();
+        hasher.write(self.label);
+
+        hasher.finish() as usize
+    }
+
+    fn get_hash(&self) -> u64 {
+        let mut hasher = HolidayHasher::new();
+        hasher.write(self.raw);
+
+        hasher.finish() as u64
+    }
+}
+
+impl<'a> TryFrom<&'a str> for Instruction<'a> {
+    type Error = String;
+
+    fn try_from(value: &'a str) -> Result<Self, Self::Error> {
+        let (label, op) = if let Some(label) = value.strip_suffix('-') {
+            (label, Op::Remove)
+        } else if let Some((label, n)) = value.split_once('=') {
+            let n = n
+                .parse::<u8>()
+                .map_err(|_| format!("invalid focal length {:?}", n))?;
+            (label, Op::Set(n))
+        } else {
+            return Err(String::from("expected 'label=N' or 'label-'"));
+        };
+
+        if label.is_empty() {
+            return Err(String::from("missing label"));
+        }
+
+        Ok(Instruction {
+            raw: value,
+            label,
+            op,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Lens<'a> {
+    label: &'a str,
+    value: u8,
+}
+
+struct LensBox<'a> {
+    lenses: Vec<Lens<'a>>,
+}
+
+impl<'a> LensBox<'a> {
+    fn new() -> Self {
+        Self { lenses: vec![] }
+    }
+
+    fn set(&mut self, label: &'a str, value: u8) {
+        if let Some(idx) = self.lenses.iter().position(|l| l.label == label) {
+            self.lenses[idx].value = value;
+        } else {
+            self.lenses.push(Lens { label, value });
+        }
+    }
+
+    fn remove(&mut self, label: &str) {
+        if let Some(idx) = self.lenses.iter().position(|l| l.label == label) {
+            self.lenses.remove(idx);
+        }
+    }
+
+    fn focal_power(&self, multiplier: usize) -> usize {
+        self.lenses
+            .iter()
+            .enumerate()
+            .map(|(idx, lens)| (idx + 1) * lens.value as usize * multiplier)
+            .sum()
+    }
+}
+
+/// Parse the initialization sequence. It is a single line, so errors report the offending step
+/// rather than the whole line.
+fn parse_instructions(input: &str) -> Result<Vec<Instruction<'_>>, ParseError> {
+    input
+        .split(',')
+        .enumerate()
+        .map(|(idx, step)| {
+            Instruction::try_from(step)
+                .map_err(|e| ParseError::new("day15", 1, step, format!("step {}: {}", idx + 1, e)))
+        })
+        .collect()
+}
+
+fn get_sum_of_hashes(instructions: &[Instruction]) -> u64 {
+    instructions.iter().map(Instruction::get_hash).sum::<u64>()
+}
+
+fn get_focusing_power(instructions: &[Instruction]) -> usize {
+    let mut boxes: Vec<LensBox> = Vec::with_capacity(256);
+    for _ in 0..256 {
+        boxes.push(LensBox::new());
+    }
+
+    for instr in instructions {
+        let box_idx = instr.get_box_id();
+
+        let box_ = &mut boxes[box_idx];
+
+        match instr.op {
+            Op::Set(v) => box_.set(instr.label, v),
+            Op::Remove => box_.remove(instr.label),
+        }
+    }
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(idx, box_)| box_.focal_power(idx + 1))
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 15 as String);
+
+    aoc_common::puzzle_fixture!("day15.txt" as String);
+
+    #[rstest]
+    fn test_hash() {
+        let mut hasher = HolidayHasher::new();
+        hasher.write("HASH");
+
+        assert_eq!(hasher.finish(), 52);
+    }
+
+    #[rstest]
+    fn test_parse_instructions(test_input: String) {
+        let instrs = parse_instructions(&test_input).unwrap();
+
+        assert_eq!(
+            instrs,
+            vec![
+                Instruction {
+                    raw: "rn=1",
+                    label: "rn",
+                    op: Op::Set(1)
+                },
+                Instruction {
+                    raw: "cm-",
+                    label: "cm",
+                    op: Op::Remove
+                },
+                Instruction {
+                    raw: "qp=3",
+                    label: "qp",
+                    op: Op::Set(3)
+                },
+                Instruction {
+                    raw: "cm=2",
+                    label: "cm",
+                    op: Op::Set(2)
+                },
+                Instruction {
+                    raw: "qp-",
+                    label: "qp",
+                    op: Op::Remove
+                },
+                Instruction {
+                    raw: "pc=4",
+                    label: "pc",
+                    op: Op::Set(4)
+                },
+                Instruction {
+                    raw: "ot=9",
+                    label: "ot",
+                    op: Op::Set(9)
+                },
+                Instruction {
+                    raw: "ab=5",
+                    label: "ab",
+                    op: Op::Set(5)
+                },
+                Instruction {
+                    raw: "pc-",
+                    label: "pc",
+                    op: Op::Remove
+                },
+                Instruction {
+                    raw: "pc=6",
+                    label: "pc",
+                    op: Op::Set(6)
+                },
+                Instruction {
+                    raw: "ot=7",
+                    label: "ot",
+                    op: Op::Set(7)
+                },
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("rn=1,cm-,qp=x", "qp=x", "step 3: invalid focal length \"x\"")]
+    #[case("rn=1,cm,qp=3", "cm", "step 2: expected 'label=N' or 'label-'")]
+    #[case("-,cm-", "-", "step 1: missing label")]
+    fn test_parse_instructions_invalid(
+        #[case] input: &str,
+        #[case] step: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            parse_instructions(input),
+            Err(ParseError::new("day15", 1, step, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: String) {
+        let instrs = parse_instructions(&test_input).unwrap();
+
+        let sum = get_sum_of_hashes(&instrs);
+
+        assert_eq!(sum, 1320);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: String) {
+        let instrs = parse_instructions(&test_input).unwrap();
+
+        assert_eq!(get_focusing_power(&instrs), 145);
+    }
+
+    fn part1(input: String) -> impl Display {
+        let instrs = parse_instructions(&input).unwrap();
+
+        get_sum_of_hashes(&instrs)
+    }
+
+    fn part2(input: String) -> impl Display {
+        let instrs = parse_instructions(&input).unwrap();
+        get_focusing_power(&instrs)
+    }
+
+    aoc_common::answer_tests!("day15", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day15", part1, part2);
+
+    aoc_common::perf_test!("day15", 5, puzzle_input(), solve);
+}
@@ -0,0 +1,813 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+use std::hash::Hash;
+use std::time::Instant;
+
+use pathfinding::prelude::strongly_connected_components;
+
+use aoc_common::progress::progress_bar;
+use aoc_common::viz::{Color, Frame, Visualizer};
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError, Point};
+
+/// Solve the puzzle input and print the answers, as the `day16` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day16.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if let Some(mut visualizer) = Visualizer::from_args() {
+        let floor = parse_floor(&input).unwrap_or_else(|e| panic!("{}", e));
+        let beam = std::env::args()
+            .find_map(|a| a.strip_prefix("--beam=").map(parse_beam))
+            .unwrap_or_default();
+
+        let caption = format!(
+            "{} tiles energized from {:?} going {:?}",
+            get_energized_tiles(&floor, beam),
+            (beam.position.x, beam.position.y),
+            beam.direction
+        );
+        visualizer
+            .draw(&render_heatmap(&floor, beam), Some(&caption))
+            .expect("Unable to draw the heatmap");
+        visualizer.finish().expect("Unable to finish the heatmap");
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let floor = parse_floor(input)?;
+
+    let p1 = get_energized_tiles(&floor, Beam::default());
+    let p2 = get_max_energized_tiles(&floor);
+
+    Ok((p1, p2))
+}
+
+type Position = Point<i32>;
+
+#[derive(Debug, PartialEq, Eq)]
+enum Tile {
+    Empty,
+    MirrorLeft,
+    MirrorRight,
+    MirrorVertical,
+    MirrorHorizontal,
+}
+
+impl TryFrom<char> for Tile {
+    type Error = String;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(Tile::Empty),
+            '\\' => Ok(Tile::MirrorLeft),
+            '/' => Ok(Tile::MirrorRight),
+            '|' => Ok(Tile::MirrorVertical),
+            '-' => Ok(Tile::MirrorHorizontal),
+            _ => Err(format!("invalid tile {:?}", value)),
+        }
+    }
+}
+
+impl Tile {
+    fn to_char(&self) -> char {
+        match self {
+            Tile::Empty => '.',
+            Tile::MirrorLeft => '\\',
+            Tile::MirrorRight => '/',
+            Tile::MirrorVertical => '|',
+            Tile::MirrorHorizontal => '-',
+        }
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    fn dx(&self) -> i32 {
+        match self {
+            Direction::Up => -1,
+            Direction::Down => 1,
+            Direction::Left => 0,
+            Direction::Right => 0,
+        }
+    }
+
+    fn dy(&self) -> i32 {
+        match self {
+            Direction::Up => 0,
+            Direction::Down => 0,
+            Direction::Left => -1,
+            Direction::Right => 1,
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+        }
+    }
+}
+
+#[derive(Debug, Hash, Clone, Copy, PartialEq, Eq)]
+struct Beam {
+    position: Position,
+    direction: Direction,
+}
+
+impl Beam {
+    /// Move the beam one tile in the given direction.
+    fn step(&self, direction: Direction) -> Beam {
+        Beam {
+            position: Point {
+                x: self.position.x + direction.dx(),
+                y: self.position.y + direction.dy(),
+            },
+            direction,
+        }
+    }
+
+    /// Get the beams coming out of a tile: the first `n` beams of the returned array, one unless
+    /// the beam is split.
+    fn tick(&self, tile: &Tile) -> ([Beam; 2], usize) {
+        let single = |direction| ([self.step(direction); 2], 1);
+
+        match tile {
+            Tile::Empty => single(self.direction),
+            Tile::MirrorLeft => single(match self.direction {
+                Direction::Up => Direction::Left,
+                Direction::Down => Direction::Right,
+                Direction::Left => Direction::Up,
+                Direction::Right => Direction::Down,
+            }),
+            Tile::MirrorRight => single(match self.direction {
+                Direction::Up => Direction::Right,
+                Direction::Down => Direction::Left,
+                Direction::Left => Direction::Down,
+                Direction::Right => Direction::Up,
+            }),
+            Tile::MirrorHorizontal => match self.direction {
+                Direction::Left | Direction::Right => single(self.direction),
+                Direction::Up | Direction::Down => {
+                    ([self.step(Direction::Left), self.step(Direction::Right)], 2)
+                }
+            },
+            Tile::MirrorVertical => match self.direction {
+                Direction::Up | Direction::Down => single(self.direction),
+                Direction::Left | Direction::Right => {
+                    ([self.step(Direction::Up), self.step(Direction::Down)], 2)
+                }
+            },
+        }
+    }
+}
+
+impl Default for Beam {
+    fn default() -> Self {
+        Beam {
+            position: Position::new(0, 0),
+            direction: Direction::Right,
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Floor {
+    height: i32,
+    width: i32,
+    tiles: Vec<Vec<Tile>>,
+}
+
+impl Floor {
+    fn is_within_bounds(&self, pos: &Position) -> bool {
+        pos.x >= 0 && pos.y >= 0 && pos.x < self.height && pos.y < self.width
+    }
+}
+
+fn parse_floor(input: &[String]) -> Result<Floor, ParseError> {
+    let width = input.first().map_or(0, |l| l.len());
+    let tiles = parse_lines("day16", input, |row| {
+        if row.len() != width {
+            return Err(format!("expected {} columns, got {}", width, row.len()));
+        }
+
+        row.chars().map(Tile::try_from).collect()
+    })?;
+
+    Ok(Floor {
+        height: input.len() as i32,
+        width: width as i32,
+        tiles,
+    })
+}
+
+fn get_energized_tiles(floor: &Floor, starting_beam: Beam) -> usize {
+    get_beam_counts(floor, starting_beam)
+        .iter()
+        .flatten()
+        .filter(|&&c| c > 0)
+        .count()
+}
+
+/// Count, for each tile, the beams going through it in distinct directions, from 0 for tiles
+/// that aren't energized up to 4.
+fn get_beam_counts(floor: &Floor, starting_beam: Beam) -> Vec<Vec<u8>> {
+    let mut counts = vec![vec![0; floor.width as usize]; floor.height as usize];
+    let mut seen_beams = BeamSet::new(floor);
+
+    let mut stack = vec![starting_beam];
+    seen_beams.insert(&starting_beam);
+
+    while let Some(b) = stack.pop() {
+        counts[b.position.x as usize][b.position.y as usize] += 1;
+
+        let tile = &floor.tiles[b.position.x as usize][b.position.y as usize];
+
+        let (next, n) = b.tick(tile);
+        for nb in next[..n].iter() {
+            if floor.is_within_bounds(&nb.position) && !seen_beams.contains(nb) {
+                seen_beams.insert(nb);
+                stack.push(*nb);
+            }
+        }
+    }
+
+    counts
+}
+
+/// Draw the floor with the energized tiles colored by the number of beams going through them,
+/// from dark red for one beam to bright yellow for four.
+fn render_heatmap(floor: &Floor, starting_beam: Beam) -> Frame {
+    const HEAT: [Color; 4] = [
+        Color::Rgb(128, 0, 0),
+        Color::Rgb(220, 50, 0),
+        Color::Rgb(255, 150, 0),
+        Color::Rgb(255, 255, 80),
+    ];
+
+    let counts = get_beam_counts(floor, starting_beam);
+    let mut frame = Frame::new(floor.width as usize, floor.height as usize, '.');
+
+    for (x, row) in floor.tiles.iter().enumerate() {
+        for (y, tile) in row.iter().enumerate() {
+            let color = match counts[x][y] {
+                0 => None,
+                n => Some(HEAT[n as usize - 1]),
+            };
+
+            // Frames are indexed by column first.
+            frame.set(y, x, tile.to_char(), color);
+        }
+    }
+
+    frame
+}
+
+/// Parse a starting beam given as `ROW,COLUMN,DIRECTION`, e.g. `0,3,down`.
+fn parse_beam(value: &str) -> Beam {
+    let parts: Vec<&str> = value.split(',').collect();
+    let [x, y, direction] = parts[..] else {
+        panic!("Invalid beam, expected ROW,COLUMN,DIRECTION: {}", value);
+    };
+
+    let direction = match direction {
+        "up" => Direction::Up,
+        "down" => Direction::Down,
+        "left" => Direction::Left,
+        "right" => Direction::Right,
+        _ => panic!("Invalid beam direction: {}", direction),
+    };
+
+    Beam {
+        position: Position::new(
+            x.parse().expect("Invalid beam row"),
+            y.parse().expect("Invalid beam column"),
+        ),
+        direction,
+    }
+}
+
+/// A fixed-size set of tiles of the floor, stored as a bitset indexed by `x * width + y`.
+#[derive(Debug, Clone)]
+struct TileSet {
+    width: i32,
+    bits: Vec<u64>,
+}
+
+impl TileSet {
+    fn new(floor: &Floor) -> Self {
+        let size = (floor.height * floor.width) as usize;
+
+        TileSet {
+            width: floor.width,
+            bits: vec![0; size.div_ceil(64)],
+        }
+    }
+
+    fn insert(&mut self, pos: &Position) {
+        let idx = (pos.x * self.width + pos.y) as usize;
+        self.bits[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn union_with(&mut self, other: &TileSet) {
+        for (a, b) in self.bits.iter_mut().zip(&other.bits) {
+            *a |= b;
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.bits.iter().map(|b| b.count_ones() as usize).sum()
+    }
+}
+
+/// A fixed-size set of beams, stored as a bitset indexed by `(x * width + y) * 4 + direction`.
+#[derive(Debug, Clone)]
+struct BeamSet {
+    width: i32,
+    bits: Vec<u64>,
+}
+
+impl BeamSet {
+    fn new(floor: &Floor) -> Self {
+        let size = (floor.height * floor.width * 4) as usize;
+
+        BeamSet {
+            width: floor.width,
+            bits: vec![0; size.div_ceil(64)],
+        }
+    }
+
+    fn index(&self, beam: &Beam) -> usize {
+        ((beam.position.x * self.width + beam.position.y) * 4) as usize + beam.direction.index()
+    }
+
+    fn insert(&mut self, beam: &Beam) {
+        let idx = self.index(beam);
+        self.bits[idx / 64] |= 1 << (idx % 64);
+    }
+
+    fn contains(&self, beam: &Beam) -> bool {
+        let idx = self.index(beam);
+        self.bits[idx / 64] & (1 << (idx % 64)) != 0
+    }
+}
+
+/// Memoized beam propagation.
+///
+/// Between splitters, a beam follows a single deterministic path, so the only places where the
+/// work branches are the splitters hit from their flat side. Each of those splitters becomes a
+/// node of a graph, and the tiles energized downstream of it are computed once, by condensing
+/// the graph into strongly connected components (beams can loop between splitters) and merging
+/// the components in reverse topological order.
+struct BeamPropagation<'a> {
+    floor: &'a Floor,
+    splitters: HashMap<Position, usize>,
+    energized: Vec<TileSet>,
+}
+
+impl<'a> BeamPropagation<'a> {
+    fn new(floor: &'a Floor) -> Self {
+        let mut splitters = HashMap::new();
+
+        for (x, row) in floor.tiles.iter().enumerate() {
+            for (y, tile) in row.iter().enumerate() {
+                if *tile == Tile::MirrorHorizontal || *tile == Tile::MirrorVertical {
+                    splitters.insert(Position::new(x as i32, y as i32), splitters.len());
+                }
+            }
+        }
+
+        let mut propagation = BeamPropagation {
+            floor,
+            splitters,
+            energized: Vec::new(),
+        };
+
+        let mut own_tiles = vec![TileSet::new(floor); propagation.splitters.len()];
+        let mut successors = vec![Vec::new(); propagation.splitters.len()];
+
+        for (pos, &id) in propagation.splitters.iter() {
+            own_tiles[id].insert(pos);
+
+            let tile = &floor.tiles[pos.x as usize][pos.y as usize];
+            let directions = if *tile == Tile::MirrorHorizontal {
+                [Direction::Left, Direction::Right]
+            } else {
+                [Direction::Up, Direction::Down]
+            };
+
+            for direction in directions {
+                let beam = Beam {
+                    position: Point {
+                        x: pos.x + direction.dx(),
+                        y: pos.y + direction.dy(),
+                    },
+                    direction,
+                };
+
+                if let Some(next) = propagation.trace(beam, &mut own_tiles[id]) {
+                    successors[id].push(next);
+                }
+            }
+        }
+
+        let nodes = (0..propagation.splitters.len()).collect::<Vec<_>>();
+        let components = strongly_connected_components(&nodes, |&n| successors[n].clone());
+
+        // Components are returned in reverse topological order, so everything downstream of a
+        // component is already resolved when we reach it.
+        let mut energized: Vec<Option<TileSet>> = vec![None; nodes.len()];
+        for component in components {
+            let mut tiles = TileSet::new(floor);
+
+            for &n in &component {
+                tiles.union_with(&own_tiles[n]);
+
+                for s in &successors[n] {
+                    if let Some(t) = &energized[*s] {
+                        tiles.union_with(t);
+                    }
+                }
+            }
+
+            for &n in &component {
+                energized[n] = Some(tiles.clone());
+            }
+        }
+
+        propagation.energized = energized.into_iter().map(Option::unwrap).collect();
+
+        propagation
+    }
+
+    /// Follow a beam until it leaves the floor, hits the flat side of a splitter or loops back on
+    /// its own path, recording the tiles it goes through. Returns the splitter that was hit, if
+    /// any.
+    fn trace(&self, mut beam: Beam, tiles: &mut TileSet) -> Option<usize> {
+        let mut seen = BeamSet::new(self.floor);
+
+        while self.floor.is_within_bounds(&beam.position) && !seen.contains(&beam) {
+            seen.insert(&beam);
+            tiles.insert(&beam.position);
+
+            let tile = &self.floor.tiles[beam.position.x as usize][beam.position.y as usize];
+            let (next, n) = beam.tick(tile);
+
+            if n > 1 {
+                return Some(self.splitters[&beam.position]);
+            }
+
+            beam = next[0];
+        }
+
+        None
+    }
+
+    fn get_energized_tiles(&self, starting_beam: Beam) -> usize {
+        let mut tiles = TileSet::new(self.floor);
+
+        if let Some(splitter) = self.trace(starting_beam, &mut tiles) {
+            tiles.union_with(&self.energized[splitter]);
+        }
+
+        tiles.len()
+    }
+}
+
+fn get_max_energized_tiles(floor: &Floor) -> usize {
+    let propagation = BeamPropagation::new(floor);
+    let progress = progress_bar(2 * (floor.width + floor.height) as u64, "starting beams");
+
+    let max = (0..floor.width)
+        .flat_map(|i| {
+            [
+                Beam {
+                    position: Position::new(0, i),
+                    direction: Direction::Down,
+                },
+                Beam {
+                    position: Position::new(floor.height - 1, i),
+                    direction: Direction::Up,
+                },
+            ]
+        })
+        .chain((0..floor.height).flat_map(|i| {
+            [
+                Beam {
+                    position: Position::new(i, 0),
+                    direction: Direction::Right,
+                },
+                Beam {
+                    position: Position::new(i, floor.width - 1),
+                    direction: Direction::Left,
+                },
+            ]
+        }))
+        .map(|b| {
+            progress.inc(1);
+            propagation.get_energized_tiles(b)
+        })
+        .max()
+        .unwrap();
+
+    progress.finish_and_clear();
+
+    max
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use aoc_common::parse_test_input;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 16);
+
+    aoc_common::puzzle_fixture!("day16.txt");
+
+    #[rstest]
+    fn test_parse_floor(test_input: Vec<String>) {
+        let floor = parse_floor(&test_input).unwrap();
+
+        assert_eq!(
+            floor,
+            Floor {
+                height: 10,
+                width: 10,
+                tiles: vec![
+                    vec![
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorLeft,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::MirrorHorizontal,
+                        Tile::Empty,
+                        Tile::MirrorLeft,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::MirrorHorizontal,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorLeft,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorRight,
+                        Tile::Empty,
+                        Tile::MirrorLeft,
+                        Tile::MirrorLeft,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::MirrorHorizontal,
+                        Tile::Empty,
+                        Tile::MirrorHorizontal,
+                        Tile::MirrorRight,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorHorizontal,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::MirrorLeft,
+                    ],
+                    vec![
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::MirrorRight,
+                        Tile::MirrorRight,
+                        Tile::Empty,
+                        Tile::MirrorVertical,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                        Tile::Empty,
+                    ],
+                ],
+            }
+        )
+    }
+
+    #[rstest]
+    #[case(1, "|.-.\\....", "expected 10 columns, got 9")]
+    #[case(4, ".....|..\\x", "invalid tile 'x'")]
+    fn test_parse_floor_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_floor(&input),
+            Err(ParseError::new("day16", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let floor = parse_floor(&test_input).unwrap();
+
+        assert_eq!(get_energized_tiles(&floor, Beam::default()), 46);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let floor = parse_floor(&test_input).unwrap();
+
+        assert_eq!(get_max_energized_tiles(&floor), 51);
+    }
+
+    #[rstest]
+    fn test_loop_through_splitters() {
+        let test_input = parse_test_input(
+            "
+            .....
+            ./-\\.
+            .|.|.
+            .\\-/.
+            .....
+            ",
+        );
+        let floor = parse_floor(&test_input).unwrap();
+
+        assert_eq!(get_energized_tiles(&floor, Beam::default()), 5);
+        assert_eq!(get_max_energized_tiles(&floor), 9);
+    }
+
+    #[rstest]
+    fn test_memoized_propagation_matches_simulation() {
+        aoc_common::require_input!("day16.txt");
+        let puzzle_input = puzzle_input();
+
+        let floor = parse_floor(&puzzle_input).unwrap();
+        let propagation = BeamPropagation::new(&floor);
+
+        for i in 0..floor.width {
+            let beam = Beam {
+                position: Position::new(floor.height - 1, i),
+                direction: Direction::Up,
+            };
+
+            assert_eq!(
+                propagation.get_energized_tiles(beam),
+                get_energized_tiles(&floor, beam)
+            );
+        }
+    }
+
+    #[rstest]
+    #[case(Beam::default())]
+    #[case(parse_beam("0,3,down"))]
+    #[case(parse_beam("9,9,left"))]
+    fn test_beam_counts_match_energized_tiles(test_input: Vec<String>, #[case] beam: Beam) {
+        let floor = parse_floor(&test_input).unwrap();
+        let propagation = BeamPropagation::new(&floor);
+
+        let counts = get_beam_counts(&floor, beam);
+
+        assert!(counts.iter().flatten().all(|&c| c <= 4));
+        assert_eq!(
+            get_energized_tiles(&floor, beam),
+            propagation.get_energized_tiles(beam)
+        );
+    }
+
+    #[rstest]
+    fn test_render_heatmap(test_input: Vec<String>) {
+        let floor = parse_floor(&test_input).unwrap();
+
+        let frame = render_heatmap(&floor, Beam::default());
+
+        assert_eq!(frame.render_plain(), test_input.join("\n") + "\n");
+        assert_eq!(frame.get(0, 0).color, Some(Color::Rgb(128, 0, 0)));
+        assert_eq!(frame.get(0, 9).color, None);
+    }
+
+    #[rstest]
+    #[case("0,3,down", Position::new(0, 3), Direction::Down)]
+    #[case("109,0,right", Position::new(109, 0), Direction::Right)]
+    fn test_parse_beam(
+        #[case] value: &str,
+        #[case] position: Position,
+        #[case] direction: Direction,
+    ) {
+        assert_eq!(
+            parse_beam(value),
+            Beam {
+                position,
+                direction
+            }
+        );
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let floor = parse_floor(&input).unwrap();
+        get_energized_tiles(&floor, Beam::default())
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let floor = parse_floor(&input).unwrap();
+        get_max_energized_tiles(&floor)
+    }
+
+    aoc_common::answer_tests!("day16", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day16", part1, part2);
+
+    aoc_common::perf_test!("day16", 20, puzzle_input(), solve);
+}
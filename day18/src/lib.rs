@@ -0,0 +1,594 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Instant;
+
+use inpt::{inpt, Inpt};
+use itertools::Itertools;
+use regex::Regex;
+
+use aoc_common::viz::{self, Frame, Visualizer};
+use aoc_common::{format_duration, parse_lines, InputSource, ParseError, Point};
+
+/// Solve the puzzle input and print the answers, as the `day18` binary does.
+pub fn run() {
+    let input = InputSource::from_args("day18.txt").read();
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    if let Some(mut visualizer) = Visualizer::from_args() {
+        let instructions = parse_instructions(&input).unwrap_or_else(|e| panic!("{}", e));
+        let colors = parse_colors(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        let caption = format!("{} cubic meters", get_dug_out_size(&instructions));
+        visualizer
+            .draw(&render_lagoon(&instructions, &colors), Some(&caption))
+            .expect("Unable to draw the lagoon");
+        visualizer.finish().expect("Unable to finish the lagoon");
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let instructions = parse_instructions(input)?;
+    let p1 = get_dug_out_size(&instructions);
+
+    let instructions = parse_fixed_instructions(input)?;
+    let p2 = get_dug_out_size(&instructions);
+
+    Ok((p1, p2))
+}
+
+type Position = Point<i64>;
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "U" => Ok(Direction::Up),
+            "D" => Ok(Direction::Down),
+            "L" => Ok(Direction::Left),
+            "R" => Ok(Direction::Right),
+            _ => Err(format!("Invalid direction: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl FromStr for Color {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.len() != 6 {
+            return Err(format!("Invalid color: {}", s));
+        }
+
+        let component = |range: std::ops::Range<usize>| {
+            s.get(range)
+                .and_then(|c| u8::from_str_radix(c, 16).ok())
+                .ok_or_else(|| format!("Invalid color: {}", s))
+        };
+
+        let r = component(0..2)?;
+        let g = component(2..4)?;
+        let b = component(4..6)?;
+
+        Ok(Self { r, g, b })
+    }
+}
+
+impl From<&Color> for viz::Color {
+    fn from(color: &Color) -> Self {
+        viz::Color::Rgb(color.r, color.g, color.b)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Inpt)]
+#[inpt(regex = r"([UDLR]) ([\d]+).*")]
+struct DigInstruction {
+    #[inpt(from_str)]
+    direction: Direction,
+    length: u64,
+}
+
+fn parse_instructions(input: &[String]) -> Result<Vec<DigInstruction>, ParseError> {
+    parse_lines("day18", input, |i| {
+        inpt::<DigInstruction>(i).map_err(|_| String::from("expected 'D N (#rrggbb)' instruction"))
+    })
+}
+
+fn parse_fixed_instructions(input: &[String]) -> Result<Vec<DigInstruction>, ParseError> {
+    let code_re = Regex::new(r"#([0-9a-fA-F]{5})([0-9a-fA-F])\)$").expect("Invalid regex");
+
+    parse_lines("day18", input, |i| {
+        let cap = code_re
+            .captures(i)
+            .ok_or("expected a '(#rrggbb)' color code")?;
+        let length = u64::from_str_radix(&cap[1], 16).unwrap();
+        let direction = match &cap[2] {
+            "0" => Direction::Right,
+            "1" => Direction::Down,
+            "2" => Direction::Left,
+            "3" => Direction::Up,
+            val => return Err(format!("invalid direction digit {:?}", val)),
+        };
+
+        Ok(DigInstruction { direction, length })
+    })
+}
+
+/// Parse the color of the trench dug by each instruction.
+fn parse_colors(input: &[String]) -> Result<Vec<Color>, ParseError> {
+    let color_re = Regex::new(r"\(#([0-9a-fA-F]{6})\)$").expect("Invalid regex");
+
+    parse_lines("day18", input, |i| {
+        let cap = color_re
+            .captures(i)
+            .ok_or("expected a '(#rrggbb)' color code")?;
+
+        cap[1].parse()
+    })
+}
+
+/// Get the corners of the trench, in the order they are dug.
+fn get_trench_vertices(instructions: &[DigInstruction]) -> Vec<Position> {
+    let mut current = Position::new(0, 0);
+    let mut vertices = Vec::with_capacity(instructions.len());
+
+    for instr in instructions {
+        let length = instr.length as i64;
+
+        match instr.direction {
+            Direction::Up => current.x -= length,
+            Direction::Down => current.x += length,
+            Direction::Left => current.y -= length,
+            Direction::Right => current.y += length,
+        }
+
+        vertices.push(current);
+    }
+
+    vertices
+}
+
+/// Get the number of cubic meters dug out, trench included. The area enclosed by the trench is
+/// computed with the shoelace formula, then Pick's theorem (`A = i + b/2 - 1`) gives the number of
+/// interior blocks, to which the `b` blocks of the trench itself are added.
+fn get_dug_out_size(instructions: &[DigInstruction]) -> u64 {
+    let vertices = get_trench_vertices(instructions);
+
+    let double_area: i64 = vertices
+        .iter()
+        .circular_tuple_windows()
+        .map(|(a, b)| a.x * b.y - b.x * a.y)
+        .sum();
+
+    let boundary: u64 = instructions.iter().map(|i| i.length).sum();
+
+    double_area.unsigned_abs() / 2 + boundary / 2 + 1
+}
+
+/// Draw the lagoon: each block of the trench in the color of the instruction that dug it, and
+/// the interior filled in. The interior is found by flooding the outside of the trench, which
+/// makes the drawing an independent check of [`get_dug_out_size`].
+fn render_lagoon(instructions: &[DigInstruction], colors: &[Color]) -> Frame {
+    let mut trench = Vec::new();
+    let mut current = Position::new(0, 0);
+
+    for (instr, color) in instructions.iter().zip(colors) {
+        for _ in 0..instr.length {
+            match instr.direction {
+                Direction::Up => current.x -= 1,
+                Direction::Down => current.x += 1,
+                Direction::Left => current.y -= 1,
+                Direction::Right => current.y += 1,
+            }
+
+            trench.push((current, color));
+        }
+    }
+
+    let (min_x, max_x) = trench
+        .iter()
+        .map(|(p, _)| p.x)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (min_y, max_y) = trench
+        .iter()
+        .map(|(p, _)| p.y)
+        .minmax()
+        .into_option()
+        .unwrap();
+    let (height, width) = ((max_x - min_x + 1) as usize, (max_y - min_y + 1) as usize);
+
+    let mut frame = Frame::new(width, height, '.');
+    for (p, color) in &trench {
+        let (x, y) = ((p.x - min_x) as usize, (p.y - min_y) as usize);
+        frame.set(y, x, '#', Some(viz::Color::from(*color)));
+    }
+
+    // Flood the outside from the corners of a one block margin around the lagoon.
+    let mut outside = vec![vec![false; width + 2]; height + 2];
+    let mut queue = VecDeque::from([(0usize, 0usize)]);
+    outside[0][0] = true;
+
+    while let Some((x, y)) = queue.pop_front() {
+        let neighbours = [
+            (x.wrapping_sub(1), y),
+            (x + 1, y),
+            (x, y.wrapping_sub(1)),
+            (x, y + 1),
+        ];
+
+        for (nx, ny) in neighbours {
+            if nx >= height + 2 || ny >= width + 2 || outside[nx][ny] {
+                continue;
+            }
+
+            let is_trench = (1..=height).contains(&nx)
+                && (1..=width).contains(&ny)
+                && frame.get(ny - 1, nx - 1).value == '#';
+            if !is_trench {
+                outside[nx][ny] = true;
+                queue.push_back((nx, ny));
+            }
+        }
+    }
+
+    for x in 0..height {
+        for y in 0..width {
+            if !outside[x + 1][y + 1] && frame.get(y, x).value == '.' {
+                frame.set(y, x, '#', Some(viz::Color::Gray));
+            }
+        }
+    }
+
+    frame
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse_test_input_as_string;
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 18);
+
+    aoc_common::puzzle_fixture!("day18.txt");
+
+    #[rstest]
+    fn test_parse_fixed_instructions(test_input: Vec<String>) {
+        let instructions = parse_fixed_instructions(&test_input).unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 461937,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 56407,
+                },
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 356671,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 863240,
+                },
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 367720,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 266681,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 577262,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 829975,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 112010,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 829975,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 491645,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 686074,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 5411,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 500254
+                }
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("R 6 (#70c71)", "expected a '(#rrggbb)' color code")]
+    #[case("R 6 (#70c7g0)", "expected a '(#rrggbb)' color code")]
+    #[case("R 6 (#70c714)", "invalid direction digit \"4\"")]
+    fn test_parse_fixed_instructions_invalid(#[case] entry: &str, #[case] expected: &str) {
+        let input = vec![String::from("R 6 (#70c710)"), String::from(entry)];
+
+        assert_eq!(
+            parse_fixed_instructions(&input),
+            Err(ParseError::new("day18", 2, entry, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_instructions(test_input: Vec<String>) {
+        let instructions = parse_instructions(&test_input).unwrap();
+
+        assert_eq!(
+            instructions,
+            vec![
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 6,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 5,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Down,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 5,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 1,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Right,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 3,
+                },
+                DigInstruction {
+                    direction: Direction::Left,
+                    length: 2,
+                },
+                DigInstruction {
+                    direction: Direction::Up,
+                    length: 2
+                }
+            ]
+        );
+    }
+
+    #[rstest]
+    #[case("X 6 (#70c710)")]
+    #[case("R six (#70c710)")]
+    fn test_parse_instructions_invalid(#[case] entry: &str) {
+        let input = vec![String::from("R 6 (#70c710)"), String::from(entry)];
+
+        assert_eq!(
+            parse_instructions(&input),
+            Err(ParseError::new(
+                "day18",
+                2,
+                entry,
+                "expected 'D N (#rrggbb)' instruction"
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_colors(test_input: Vec<String>) {
+        let colors = parse_colors(&test_input).unwrap();
+
+        assert_eq!(colors.len(), 14);
+        assert_eq!(
+            colors[0],
+            Color {
+                r: 0x70,
+                g: 0xc7,
+                b: 0x10
+            }
+        );
+        assert_eq!(
+            colors[13],
+            Color {
+                r: 0x7a,
+                g: 0x21,
+                b: 0xe3
+            }
+        );
+    }
+
+    #[rstest]
+    #[case("R 6 (#70c71)")]
+    #[case("R 6 (#70c7g0)")]
+    #[case("R 6 70c710")]
+    fn test_parse_colors_invalid(#[case] entry: &str) {
+        let input = vec![String::from("R 6 (#70c710)"), String::from(entry)];
+
+        assert_eq!(
+            parse_colors(&input),
+            Err(ParseError::new(
+                "day18",
+                2,
+                entry,
+                "expected a '(#rrggbb)' color code"
+            ))
+        );
+    }
+
+    #[rstest]
+    fn test_render_lagoon(test_input: Vec<String>) {
+        let instructions = parse_instructions(&test_input).unwrap();
+        let colors = parse_colors(&test_input).unwrap();
+
+        let frame = render_lagoon(&instructions, &colors);
+
+        assert_eq!(
+            frame.render_plain(),
+            parse_test_input_as_string(
+                "
+                #######
+                #######
+                #######
+                ..#####
+                ..#####
+                #######
+                #####..
+                #######
+                .######
+                .######
+                "
+            ) + "\n"
+        );
+        assert_eq!(
+            frame.get(1, 0).color,
+            Some(viz::Color::Rgb(0x70, 0xc7, 0x10))
+        );
+        assert_eq!(frame.get(1, 1).color, Some(viz::Color::Gray));
+    }
+
+    #[rstest]
+    fn test_render_lagoon_matches_dug_out_size() {
+        aoc_common::require_input!("day18.txt");
+        let puzzle_input = puzzle_input();
+
+        let instructions = parse_instructions(&puzzle_input).unwrap();
+        let colors = parse_colors(&puzzle_input).unwrap();
+
+        let frame = render_lagoon(&instructions, &colors);
+        let blocks = frame.render_plain().chars().filter(|&c| c == '#').count();
+
+        assert_eq!(blocks as u64, get_dug_out_size(&instructions));
+    }
+
+    #[rstest]
+    fn test_get_trench_vertices(test_input: Vec<String>) {
+        let instructions = parse_instructions(&test_input).unwrap();
+        let vertices = get_trench_vertices(&instructions);
+
+        let expected = vec![
+            Point { x: 0, y: 6 },
+            Point { x: 5, y: 6 },
+            Point { x: 5, y: 4 },
+            Point { x: 7, y: 4 },
+            Point { x: 7, y: 6 },
+            Point { x: 9, y: 6 },
+            Point { x: 9, y: 1 },
+            Point { x: 7, y: 1 },
+            Point { x: 7, y: 0 },
+            Point { x: 5, y: 0 },
+            Point { x: 5, y: 2 },
+            Point { x: 2, y: 2 },
+            Point { x: 2, y: 0 },
+            Point { x: 0, y: 0 },
+        ];
+
+        assert_eq!(vertices, expected);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let instructions = parse_instructions(&test_input).unwrap();
+
+        let res = get_dug_out_size(&instructions);
+
+        assert_eq!(res, 62);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let instructions = parse_fixed_instructions(&test_input).unwrap();
+
+        let res = get_dug_out_size(&instructions);
+
+        assert_eq!(res, 952408144115);
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let instructions = parse_instructions(&input).unwrap();
+
+        get_dug_out_size(&instructions)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let instructions = parse_fixed_instructions(&input).unwrap();
+
+        get_dug_out_size(&instructions)
+    }
+
+    aoc_common::answer_tests!("day18", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day18", part1, part2);
+
+    aoc_common::perf_test!("day18", 10, puzzle_input(), solve);
+}
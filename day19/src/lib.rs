@@ -0,0 +1,829 @@
+use inpt::{inpt, Inpt};
+use itertools::Itertools;
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::time::Instant;
+
+use aoc_common::dump::dump_parsed_flag;
+use aoc_common::viz::Color;
+use aoc_common::{format_duration, trace_flag, InputSource, ParseError};
+use regex::Regex;
+use serde::Serialize;
+use tracing::trace;
+
+/// Where the workflows are written with `--export dot`.
+const DOT_PATH: &str = "day19.dot";
+
+/// Solve the puzzle input and print the answers, as the `day19` binary does.
+pub fn run() {
+    if std::env::args().any(|a| a == "-v" || a == "--verbose") {
+        tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::TRACE)
+            .with_writer(std::io::stderr)
+            .init();
+    }
+
+    let input = InputSource::from_args("day19.txt").read();
+
+    if let Some(format) = dump_parsed_flag() {
+        let system = parse_system(&input).unwrap_or_else(|e| panic!("{}", e));
+        print!("{}", format.dump(&system));
+        return;
+    }
+
+    let start = Instant::now();
+
+    let (r1, r2) = solve(input.as_slice()).unwrap_or_else(|e| panic!("{}", e));
+
+    let t = start.elapsed().as_nanos();
+
+    println!("Part 1: {}", r1);
+    println!("Part 2: {}", r2);
+    println!("Duration: {}", format_duration(t));
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.windows(2).any(|a| a[0] == "--export" && a[1] == "dot") {
+        let system = parse_system(&input).unwrap_or_else(|e| panic!("{}", e));
+        std::fs::write(DOT_PATH, render_dot(&system))
+            .unwrap_or_else(|e| panic!("Unable to write {}: {}", DOT_PATH, e));
+        println!("Workflows written to {}", DOT_PATH);
+    }
+
+    if trace_flag() {
+        let system = parse_system(&input).unwrap_or_else(|e| panic!("{}", e));
+
+        println!();
+        println!("Visited workflows:");
+        for part in &system.parts {
+            let (visited, action) = get_visited_workflows(part, &system);
+            let result = if action == Action::Accept { "A" } else { "R" };
+
+            println!(
+                "  {{x={},m={},a={},s={}}}: {} -> {}",
+                part.x,
+                part.m,
+                part.a,
+                part.s,
+                visited
+                    .iter()
+                    .map(|&id| &system.workflows[id].name)
+                    .join(" -> "),
+                result
+            );
+        }
+    }
+}
+
+/// Solve both parts of the puzzle.
+pub fn solve(input: &[String]) -> Result<(impl Display, impl Display), ParseError> {
+    let system = parse_system(input)?;
+
+    let p1 = get_total_of_accepted_parts(&system);
+    let p2 = get_possible_combinations(&system);
+
+    Ok((p1, p2))
+}
+
+/// Index of a workflow in the system's workflow list.
+type WorkflowId = usize;
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct System {
+    workflows: Vec<Workflow>,
+    start: WorkflowId,
+    parts: Vec<Part>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct Rule {
+    condition: Option<Condition>,
+    action: Action,
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct Condition {
+    category: Category,
+    op: Op,
+    val: usize,
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let op = match self.op {
+            Op::Lt => '<',
+            Op::Gt => '>',
+        };
+
+        write!(f, "{}{}{}", self.category, op, self.val)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+enum Category {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<&str> for Category {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "x" => Ok(Category::X),
+            "m" => Ok(Category::M),
+            "a" => Ok(Category::A),
+            "s" => Ok(Category::S),
+            _ => Err(format!("Invalid category: {}", value)),
+        }
+    }
+}
+
+impl Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Category::X => 'x',
+            Category::M => 'm',
+            Category::A => 'a',
+            Category::S => 's',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Serialize)]
+enum Op {
+    Lt,
+    Gt,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+enum Action {
+    Accept,
+    Reject,
+    Process(WorkflowId),
+}
+
+#[derive(Debug, Eq, PartialEq, Inpt, Serialize)]
+#[inpt(regex = r"\{x=([\d]+),m=([\d]+),a=([\d]+),s=([\d]+)\}")]
+struct Part {
+    x: usize,
+    m: usize,
+    a: usize,
+    s: usize,
+}
+
+impl Part {
+    fn get(&self, category: Category) -> usize {
+        match category {
+            Category::X => self.x,
+            Category::M => self.m,
+            Category::A => self.a,
+            Category::S => self.s,
+        }
+    }
+
+    fn value(&self) -> usize {
+        self.x + self.m + self.a + self.s
+    }
+}
+
+fn parse_system(input: &[String]) -> Result<System, ParseError> {
+    let error = |idx: usize, reason: String| {
+        ParseError::new("day19", idx + 1, input.get(idx).map_or("", |l| l), reason)
+    };
+
+    let raw_workflows = input.iter().take_while(|e| !e.is_empty()).collect_vec();
+
+    // Workflows are referenced before being defined, so the ids are assigned before parsing them.
+    let ids: HashMap<&str, WorkflowId> = raw_workflows
+        .iter()
+        .enumerate()
+        .map(|(id, e)| match e.split_once('{') {
+            Some((name, _)) => Ok((name, id)),
+            None => Err(error(id, String::from("expected 'name{rules}' workflow"))),
+        })
+        .collect::<Result<_, _>>()?;
+
+    let workflows = raw_workflows
+        .iter()
+        .enumerate()
+        .map(|(idx, e)| parse_workflow(e, &ids).map_err(|r| error(idx, r)))
+        .collect::<Result<_, _>>()?;
+    let start = *ids
+        .get("in")
+        .ok_or_else(|| error(raw_workflows.len(), String::from("missing 'in' workflow")))?;
+
+    let parts = input
+        .iter()
+        .enumerate()
+        .skip(raw_workflows.len() + 1)
+        .map(|(idx, i)| {
+            inpt::<Part>(i)
+                .map_err(|_| error(idx, String::from("expected '{x=N,m=N,a=N,s=N}' part")))
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(System {
+        workflows,
+        start,
+        parts,
+    })
+}
+
+fn parse_workflow(entry: &str, ids: &HashMap<&str, WorkflowId>) -> Result<Workflow, String> {
+    let (name, rules) = entry
+        .split_once('{')
+        .and_then(|(name, rules)| Some((name, rules.strip_suffix('}')?)))
+        .ok_or("expected 'name{rules}' workflow")?;
+
+    let rules = rules
+        .split(',')
+        .map(|rule| parse_rule(rule, ids))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Workflow {
+        name: name.to_string(),
+        rules,
+    })
+}
+
+fn parse_rule(val: &str, ids: &HashMap<&str, WorkflowId>) -> Result<Rule, String> {
+    if let Some((condition, action)) = val.split_once(':') {
+        let condition = Some(parse_condition(condition)?);
+        let action = parse_action(action, ids)?;
+
+        Ok(Rule { condition, action })
+    } else {
+        let action = parse_action(val, ids)?;
+        Ok(Rule {
+            condition: None,
+            action,
+        })
+    }
+}
+
+fn parse_condition(val: &str) -> Result<Condition, String> {
+    let re = Regex::new(r"^([a-zA-Z]+)([<>])([0-9]+)$").expect("Invalid regex");
+
+    let caps = re
+        .captures(val)
+        .ok_or_else(|| format!("invalid condition {:?}", val))?;
+
+    let category = caps[1].try_into()?;
+    let op = match &caps[2] {
+        "<" => Op::Lt,
+        ">" => Op::Gt,
+        _ => unreachable!(),
+    };
+    let val = caps[3]
+        .parse::<usize>()
+        .map_err(|_| format!("invalid value {:?}", &caps[3]))?;
+
+    Ok(Condition { category, op, val })
+}
+
+fn parse_action(val: &str, ids: &HashMap<&str, WorkflowId>) -> Result<Action, String> {
+    match val {
+        "A" => Ok(Action::Accept),
+        "R" => Ok(Action::Reject),
+        workflow => ids
+            .get(workflow)
+            .map(|&id| Action::Process(id))
+            .ok_or_else(|| format!("unknown workflow {:?}", workflow)),
+    }
+}
+
+fn is_accepted(part: &Part, system: &System) -> bool {
+    get_visited_workflows(part, system).1 == Action::Accept
+}
+
+/// Get the workflows a part goes through, in order, and whether it ends up accepted or rejected.
+fn get_visited_workflows(part: &Part, system: &System) -> (Vec<WorkflowId>, Action) {
+    let mut visited = vec![system.start];
+
+    loop {
+        match get_action(part, &system.workflows[*visited.last().unwrap()]) {
+            Action::Process(id) => visited.push(id),
+            action => return (visited, action),
+        }
+    }
+}
+
+fn get_action(part: &Part, workflow: &Workflow) -> Action {
+    for rule in &workflow.rules {
+        if let Some(c) = &rule.condition {
+            let part_value = part.get(c.category);
+
+            match c.op {
+                Op::Lt => {
+                    if part_value < c.val {
+                        return rule.action;
+                    }
+                }
+                Op::Gt => {
+                    if part_value > c.val {
+                        return rule.action;
+                    }
+                }
+            }
+        } else {
+            return rule.action;
+        }
+    }
+
+    Action::Reject
+}
+
+fn get_total_of_accepted_parts(system: &System) -> usize {
+    system
+        .parts
+        .par_iter()
+        .filter_map(|p| {
+            if is_accepted(p, system) {
+                Some(p.value())
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// A set of parts, with the values of each category within an inclusive range.
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct TheoreticalPart {
+    min_x: usize,
+    max_x: usize,
+    min_m: usize,
+    max_m: usize,
+    min_a: usize,
+    max_a: usize,
+    min_s: usize,
+    max_s: usize,
+}
+
+impl TheoreticalPart {
+    fn new() -> Self {
+        TheoreticalPart {
+            min_x: 1,
+            max_x: 4000,
+            min_m: 1,
+            max_m: 4000,
+            min_a: 1,
+            max_a: 4000,
+            min_s: 1,
+            max_s: 4000,
+        }
+    }
+
+    fn bounds_mut(&mut self, category: Category) -> (&mut usize, &mut usize) {
+        match category {
+            Category::X => (&mut self.min_x, &mut self.max_x),
+            Category::M => (&mut self.min_m, &mut self.max_m),
+            Category::A => (&mut self.min_a, &mut self.max_a),
+            Category::S => (&mut self.min_s, &mut self.max_s),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.min_x > self.max_x
+            || self.min_m > self.max_m
+            || self.min_a > self.max_a
+            || self.min_s > self.max_s
+    }
+
+    fn count(&self) -> usize {
+        (self.max_x - self.min_x + 1)
+            * (self.max_m - self.min_m + 1)
+            * (self.max_a - self.min_a + 1)
+            * (self.max_s - self.min_s + 1)
+    }
+
+    /// Split the parts between the ones matching a condition and the others, either being `None`
+    /// when empty.
+    fn split(&self, condition: &Condition) -> (Option<Self>, Option<Self>) {
+        let mut matching = self.clone();
+        let mut others = self.clone();
+
+        let (matching_min, matching_max) = matching.bounds_mut(condition.category);
+        let (others_min, others_max) = others.bounds_mut(condition.category);
+
+        match condition.op {
+            Op::Lt => {
+                *matching_max = (*matching_max).min(condition.val.saturating_sub(1));
+                *others_min = (*others_min).max(condition.val);
+            }
+            Op::Gt => {
+                *matching_min = (*matching_min).max(condition.val + 1);
+                *others_max = (*others_max).min(condition.val);
+            }
+        }
+
+        (
+            Some(matching).filter(|p| !p.is_empty()),
+            Some(others).filter(|p| !p.is_empty()),
+        )
+    }
+}
+
+fn get_possible_combinations(system: &System) -> usize {
+    let part = TheoreticalPart::new();
+    let workflow = &system.workflows[system.start];
+
+    let parts = get_possibles(system, part, workflow);
+
+    for (i, p) in parts.iter().enumerate() {
+        trace!(i, ?p, "possible part");
+    }
+
+    parts.iter().map(TheoreticalPart::count).sum()
+}
+
+/// Get the accepted parts among the given ones, going through the rules of the workflow in order:
+/// each rule handles the parts matching its condition, and leaves the others to the next rules.
+/// The returned sets of parts are disjoint.
+fn get_possibles(
+    system: &System,
+    part: TheoreticalPart,
+    workflow: &Workflow,
+) -> Vec<TheoreticalPart> {
+    let mut possibles = Vec::new();
+    let mut remaining = Some(part);
+
+    for rule in &workflow.rules {
+        let Some(part) = remaining.take() else {
+            break;
+        };
+
+        let matching = match &rule.condition {
+            Some(c) => {
+                let (matching, others) = part.split(c);
+                remaining = others;
+                matching
+            }
+            None => Some(part),
+        };
+
+        let Some(p) = matching else {
+            continue;
+        };
+
+        match rule.action {
+            Action::Accept => possibles.push(p),
+            Action::Reject => {}
+            Action::Process(id) => {
+                possibles.append(&mut get_possibles(system, p, &system.workflows[id]))
+            }
+        }
+    }
+
+    possibles
+}
+
+/// Draw the workflows as a Graphviz graph, with an edge per rule labeled with its position in the
+/// workflow and its condition, and the accepted and rejected parts ending in terminal nodes.
+fn render_dot(system: &System) -> String {
+    let mut dot = String::from("digraph workflows {\n");
+    for (name, color) in [("A", Color::Green), ("R", Color::Red)] {
+        dot.push_str(&format!(
+            "  \"{}\" [shape=doublecircle, style=filled, fillcolor=\"{}\"];\n",
+            name,
+            color.hex()
+        ));
+    }
+
+    for (id, workflow) in system.workflows.iter().enumerate() {
+        if id == system.start {
+            dot.push_str(&format!(
+                "  \"{}\" [shape=box, style=bold];\n",
+                workflow.name
+            ));
+        } else {
+            dot.push_str(&format!("  \"{}\" [shape=box];\n", workflow.name));
+        }
+    }
+
+    for workflow in &system.workflows {
+        for (idx, rule) in workflow.rules.iter().enumerate() {
+            let target = match rule.action {
+                Action::Accept => "A",
+                Action::Reject => "R",
+                Action::Process(id) => &system.workflows[id].name,
+            };
+            let condition = match &rule.condition {
+                Some(c) => c.to_string(),
+                None => String::from("else"),
+            };
+
+            dot.push_str(&format!(
+                "  \"{}\" -> \"{}\" [label=\"{}. {}\"];\n",
+                workflow.name,
+                target,
+                idx + 1,
+                condition
+            ));
+        }
+    }
+
+    dot.push_str("}\n");
+
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    aoc_common::example_fixture!(day = 19);
+
+    aoc_common::puzzle_fixture!("day19.txt");
+
+    /// Builds the expected workflows of a test. Targets are given by name and resolved to
+    /// workflow ids by [`SystemBuilder::build`].
+    struct WorkflowBuilder {
+        name: &'static str,
+        rules: Vec<(Option<Condition>, &'static str)>,
+    }
+
+    impl WorkflowBuilder {
+        fn new(name: &'static str) -> Self {
+            Self {
+                name,
+                rules: Vec::new(),
+            }
+        }
+
+        fn rule(mut self, category: char, op: Op, val: usize, target: &'static str) -> Self {
+            let category = Category::try_from(category.to_string().as_str()).unwrap();
+            let condition = Condition { category, op, val };
+            self.rules.push((Some(condition), target));
+            self
+        }
+
+        fn lt(self, category: char, val: usize, target: &'static str) -> Self {
+            self.rule(category, Op::Lt, val, target)
+        }
+
+        fn gt(self, category: char, val: usize, target: &'static str) -> Self {
+            self.rule(category, Op::Gt, val, target)
+        }
+
+        fn accept_if_lt(self, category: char, val: usize) -> Self {
+            self.lt(category, val, "A")
+        }
+
+        fn accept_if_gt(self, category: char, val: usize) -> Self {
+            self.gt(category, val, "A")
+        }
+
+        fn reject_if_gt(self, category: char, val: usize) -> Self {
+            self.gt(category, val, "R")
+        }
+
+        fn fallback(mut self, target: &'static str) -> Self {
+            self.rules.push((None, target));
+            self
+        }
+    }
+
+    #[derive(Default)]
+    struct SystemBuilder {
+        workflows: Vec<WorkflowBuilder>,
+        parts: Vec<Part>,
+    }
+
+    impl SystemBuilder {
+        fn workflow(mut self, workflow: WorkflowBuilder) -> Self {
+            self.workflows.push(workflow);
+            self
+        }
+
+        fn part(mut self, x: usize, m: usize, a: usize, s: usize) -> Self {
+            self.parts.push(Part { x, m, a, s });
+            self
+        }
+
+        fn build(self) -> System {
+            let names: Vec<&str> = self.workflows.iter().map(|w| w.name).collect();
+            let id = |name: &str| {
+                names
+                    .iter()
+                    .position(|&n| n == name)
+                    .unwrap_or_else(|| panic!("Unknown workflow: {}", name))
+            };
+            let action = |target: &str| match target {
+                "A" => Action::Accept,
+                "R" => Action::Reject,
+                name => Action::Process(id(name)),
+            };
+
+            let workflows = self
+                .workflows
+                .into_iter()
+                .map(|w| Workflow {
+                    name: w.name.to_string(),
+                    rules: w
+                        .rules
+                        .into_iter()
+                        .map(|(condition, target)| Rule {
+                            condition,
+                            action: action(target),
+                        })
+                        .collect(),
+                })
+                .collect();
+
+            System {
+                workflows,
+                start: id("in"),
+                parts: self.parts,
+            }
+        }
+    }
+
+    #[rstest]
+    fn test_parse_system(test_input: Vec<String>) {
+        let system = parse_system(&test_input).unwrap();
+
+        let expected = SystemBuilder::default()
+            .workflow(
+                WorkflowBuilder::new("px")
+                    .lt('a', 2006, "qkq")
+                    .accept_if_gt('m', 2090)
+                    .fallback("rfg"),
+            )
+            .workflow(
+                WorkflowBuilder::new("pv")
+                    .reject_if_gt('a', 1716)
+                    .fallback("A"),
+            )
+            .workflow(
+                WorkflowBuilder::new("lnx")
+                    .accept_if_gt('m', 1548)
+                    .fallback("A"),
+            )
+            .workflow(
+                WorkflowBuilder::new("rfg")
+                    .lt('s', 537, "gd")
+                    .reject_if_gt('x', 2440)
+                    .fallback("A"),
+            )
+            .workflow(
+                WorkflowBuilder::new("qs")
+                    .accept_if_gt('s', 3448)
+                    .fallback("lnx"),
+            )
+            .workflow(
+                WorkflowBuilder::new("qkq")
+                    .accept_if_lt('x', 1416)
+                    .fallback("crn"),
+            )
+            .workflow(
+                WorkflowBuilder::new("crn")
+                    .accept_if_gt('x', 2662)
+                    .fallback("R"),
+            )
+            .workflow(
+                WorkflowBuilder::new("in")
+                    .lt('s', 1351, "px")
+                    .fallback("qqz"),
+            )
+            .workflow(
+                WorkflowBuilder::new("qqz")
+                    .gt('s', 2770, "qs")
+                    .lt('m', 1801, "hdj")
+                    .fallback("R"),
+            )
+            .workflow(
+                WorkflowBuilder::new("gd")
+                    .reject_if_gt('a', 3333)
+                    .fallback("R"),
+            )
+            .workflow(
+                WorkflowBuilder::new("hdj")
+                    .accept_if_gt('m', 838)
+                    .fallback("pv"),
+            )
+            .part(787, 2655, 1222, 2876)
+            .part(1679, 44, 2067, 496)
+            .part(2036, 264, 79, 2244)
+            .part(2461, 1339, 466, 291)
+            .part(2127, 1623, 2188, 1013)
+            .build();
+
+        assert_eq!(expected.start, 7);
+        assert_eq!(system, expected);
+    }
+
+    #[rstest]
+    #[case(0, "px{a<2006:qkq,m>2090:A,rfg", "expected 'name{rules}' workflow")]
+    #[case(1, "pv{a>1716:R,A,}", "unknown workflow \"\"")]
+    #[case(2, "lnx{m>1548:A,A:R}", "invalid condition \"A\"")]
+    #[case(3, "rfg{q<537:gd,x>2440:R,A}", "Invalid category: q")]
+    #[case(4, "qs{s=3448:A,lnx}", "invalid condition \"s=3448\"")]
+    #[case(5, "qkq{x<1416:A,cxn}", "unknown workflow \"cxn\"")]
+    #[case(12, "{x=787,m=2655,a=1222}", "expected '{x=N,m=N,a=N,s=N}' part")]
+    fn test_parse_system_invalid(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] line: &str,
+        #[case] expected: &str,
+    ) {
+        let mut input = test_input;
+        input[idx] = String::from(line);
+
+        assert_eq!(
+            parse_system(&input),
+            Err(ParseError::new("day19", idx + 1, line, expected))
+        );
+    }
+
+    #[rstest]
+    fn test_parse_system_without_start(test_input: Vec<String>) {
+        let mut input = test_input;
+        input[7] = String::from("ni{s<1351:px,qqz}");
+
+        assert_eq!(
+            parse_system(&input),
+            Err(ParseError::new("day19", 12, "", "missing 'in' workflow"))
+        );
+    }
+
+    #[rstest]
+    #[case(0, vec!["in", "qqz", "qs", "lnx"], Action::Accept)]
+    #[case(1, vec!["in", "px", "rfg", "gd"], Action::Reject)]
+    #[case(2, vec!["in", "qqz", "hdj", "pv"], Action::Accept)]
+    fn test_get_visited_workflows(
+        test_input: Vec<String>,
+        #[case] idx: usize,
+        #[case] expected: Vec<&str>,
+        #[case] expected_action: Action,
+    ) {
+        let system = parse_system(&test_input).unwrap();
+        let (visited, action) = get_visited_workflows(&system.parts[idx], &system);
+
+        let names = visited
+            .iter()
+            .map(|&id| system.workflows[id].name.as_str())
+            .collect_vec();
+        assert_eq!(names, expected);
+        assert_eq!(action, expected_action);
+    }
+
+    #[rstest]
+    fn test_p1(test_input: Vec<String>) {
+        let system = parse_system(&test_input).unwrap();
+        let res = get_total_of_accepted_parts(&system);
+
+        assert_eq!(res, 19114);
+    }
+
+    #[rstest]
+    fn test_p2(test_input: Vec<String>) {
+        let system = parse_system(&test_input).unwrap();
+        let res = get_possible_combinations(&system);
+
+        assert_eq!(res, 167409079868000);
+    }
+
+    #[rstest]
+    #[case(Condition { category: Category::X, op: Op::Lt, val: 1416 }, "x<1416")]
+    #[case(Condition { category: Category::S, op: Op::Gt, val: 2770 }, "s>2770")]
+    fn test_condition_display(#[case] condition: Condition, #[case] expected: &str) {
+        assert_eq!(condition.to_string(), expected);
+    }
+
+    #[rstest]
+    fn test_render_dot(test_input: Vec<String>) {
+        let system = parse_system(&test_input).unwrap();
+
+        insta::assert_snapshot!(render_dot(&system));
+    }
+
+    fn part1(input: Vec<String>) -> impl Display {
+        let system = parse_system(&input).unwrap();
+        get_total_of_accepted_parts(&system)
+    }
+
+    fn part2(input: Vec<String>) -> impl Display {
+        let system = parse_system(&input).unwrap();
+        get_possible_combinations(&system)
+    }
+
+    aoc_common::answer_tests!("day19", puzzle_input(), part1, part2);
+
+    aoc_common::example_tests!("day19", part1, part2);
+
+    aoc_common::perf_test!("day19", 250, puzzle_input(), solve);
+}